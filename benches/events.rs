@@ -0,0 +1,56 @@
+use binance_async_api::websocket::{spot, usdm};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use serde::Deserialize;
+
+// Parsing is the only difference between `BinanceWebsocket`'s owned stream
+// and `next_borrowed`, so both are compared on the same frames
+
+const AGG_TRADE: &str = r#"{"e":"aggTrade","E":1700000000123,"a":2149912583,"s":"BTCUSDT","p":"37012.40","q":"0.015","f":4271831720,"l":4271831722,"T":1700000000121,"m":true}"#;
+
+const BOOK_TICKER: &str = r#"{"e":"bookTicker","u":3682854202063,"s":"BTCUSDT","b":"37012.40","B":"6.112","a":"37012.50","A":"2.307","T":1700000000121,"E":1700000000123}"#;
+
+fn depth_frame() -> String {
+    let levels = |start: f64, step: f64| {
+        (0..20)
+            .map(|i| {
+                format!(
+                    r#"["{:.2}","{:.3}"]"#,
+                    start + step * i as f64,
+                    0.1 + i as f64
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    format!(
+        r#"{{"e":"depthUpdate","E":1700000000123,"T":1700000000121,"s":"BTCUSDT","U":3682854201000,"u":3682854202063,"pu":3682854200999,"b":[{}],"a":[{}]}}"#,
+        levels(37012.4, -0.1),
+        levels(37012.5, 0.1)
+    )
+}
+
+fn compare<'a, O, B>(c: &mut Criterion, name: &str, text: &'a str)
+where
+    O: Deserialize<'a>,
+    B: Deserialize<'a>,
+{
+    let mut group = c.benchmark_group(name);
+    group.bench_function("owned", |b| {
+        b.iter(|| serde_json::from_str::<O>(black_box(text)).unwrap())
+    });
+    group.bench_function("borrowed", |b| {
+        b.iter(|| serde_json::from_str::<B>(black_box(text)).unwrap())
+    });
+    group.finish();
+}
+
+fn events(c: &mut Criterion) {
+    let depth = depth_frame();
+    compare::<usdm::AggTradeEvent, usdm::AggTradeEventRef>(c, "usdm_agg_trade", AGG_TRADE);
+    compare::<usdm::BookTickerEvent, usdm::BookTickerEventRef>(c, "usdm_book_ticker", BOOK_TICKER);
+    compare::<usdm::DiffDepthEvent, usdm::DiffDepthEventRef>(c, "usdm_diff_depth", &depth);
+    compare::<spot::AggTradeEvent, spot::AggTradeEventRef>(c, "spot_agg_trade", AGG_TRADE);
+}
+
+criterion_group!(benches, events);
+criterion_main!(benches);
@@ -0,0 +1,58 @@
+use binance_async_api::websocket::{spot, usdm};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use serde::de::DeserializeOwned;
+
+// serde_json against simd-json on sample frames, one frame per line. Each
+// iteration parses a fresh copy as simd-json overwrites its input, and reuses
+// simd-json's buffers across frames like the connections do.
+//
+// The frames in benches/data are recorded from the BTCUSDT streams with
+// `cargo run --example record_frames`, which overwrites them with live ones.
+// The files committed so far are generated, not recorded: they follow the
+// layout of live frames (fields, number formats, depth levels per update)
+// with made up prices, sizes and ids. Record fresh ones before reading more
+// into the timings than a comparison of the backends on that shape.
+
+fn frames(file: &str) -> Vec<String> {
+    let path = format!("{}/benches/data/{}", env!("CARGO_MANIFEST_DIR"), file);
+    let data = std::fs::read_to_string(path).unwrap();
+    data.lines().map(str::to_string).collect()
+}
+
+fn compare<E: DeserializeOwned>(c: &mut Criterion, name: &str, frames: &[String]) {
+    let mut group = c.benchmark_group(name);
+    group.bench_function("serde_json", |b| {
+        b.iter_batched(
+            || frames.to_vec(),
+            |frames| {
+                for frame in &frames {
+                    serde_json::from_str::<E>(frame).unwrap();
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("simd_json", |b| {
+        b.iter_batched(
+            || frames.to_vec(),
+            |frames| {
+                let mut buffers = simd_json::Buffers::default();
+                for frame in frames {
+                    let mut frame = frame.into_bytes();
+                    simd_json::serde::from_slice_with_buffers::<E>(&mut frame, &mut buffers)
+                        .unwrap();
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn backends(c: &mut Criterion) {
+    compare::<usdm::DiffDepthEvent>(c, "usdm_depth", &frames("usdm_depth.jsonl"));
+    compare::<spot::TradeEvent>(c, "spot_trade", &frames("spot_trade.jsonl"));
+}
+
+criterion_group!(benches, backends);
+criterion_main!(benches);
@@ -0,0 +1,47 @@
+use futures_util::StreamExt;
+use std::{fs::File, io::Write};
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+// Records the frames the json_backends bench parses, as received, one text
+// frame per line:
+//
+//     cargo run --example record_frames [frames per stream]
+//
+// Overwrites benches/data/usdm_depth.jsonl and benches/data/spot_trade.jsonl.
+
+const STREAMS: &[(&str, &str)] = &[
+    (
+        "wss://fstream.binance.com/ws/btcusdt@depth@0ms",
+        "usdm_depth.jsonl",
+    ),
+    (
+        "wss://stream.binance.com:9443/ws/btcusdt@trade",
+        "spot_trade.jsonl",
+    ),
+];
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let count = std::env::args()
+        .nth(1)
+        .map(|count| count.parse().expect("frames per stream"))
+        .unwrap_or(500);
+    for (url, file) in STREAMS {
+        let (mut stream, _) = connect_async(*url).await.unwrap();
+        let path = format!("{}/benches/data/{}", env!("CARGO_MANIFEST_DIR"), file);
+        let mut out = File::create(&path).unwrap();
+        let mut recorded = 0;
+        while recorded < count {
+            match stream.next().await {
+                Some(Ok(Message::Text(frame))) => {
+                    writeln!(out, "{}", frame).unwrap();
+                    recorded += 1;
+                }
+                Some(Ok(_)) => {}
+                Some(Err(e)) => panic!("{}: {}", url, e),
+                None => panic!("{}: closed after {} frames", url, recorded),
+            }
+        }
+        println!("{} frames of {} in {}", recorded, url, path);
+    }
+}
@@ -0,0 +1,202 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenTree;
+use quote::quote;
+use syn::{
+    ext::IdentExt, parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Error, Expr,
+    ExprLit, Fields, Ident, Lit, Meta,
+};
+
+// Names the fields of a model the way Binance sends them, and under
+// `readable_names` serializes them by their Rust names instead. Either name
+// deserializes. The Binance name follows the rule given, camelCase or
+// UPPERCASE, unless the field sets its own:
+//
+//     #[wire_names(camelCase)]
+//     #[derive(Deserialize, Serialize)]
+//     pub struct OrderBookResponse {
+//         pub last_update_id: u64,       // lastUpdateId
+//         #[wire = "E"]
+//         pub message_output_time: u64,  // E
+//     }
+//
+// Goes above the derives so that they see the generated attributes.
+#[proc_macro_attribute]
+pub fn wire_names(args: TokenStream, item: TokenStream) -> TokenStream {
+    let rule = match args.is_empty() {
+        true => None,
+        false => Some(parse_macro_input!(args as Ident)),
+    };
+    let mut input = parse_macro_input!(item as DeriveInput);
+    match expand(rule, &mut input) {
+        Ok(()) => quote!(#input).into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Rule {
+    Verbatim,
+    CamelCase,
+    Uppercase,
+}
+
+impl Rule {
+    fn parse(rule: Option<Ident>) -> syn::Result<Self> {
+        match rule {
+            None => Ok(Rule::Verbatim),
+            Some(rule) if rule == "camelCase" => Ok(Rule::CamelCase),
+            Some(rule) if rule == "UPPERCASE" => Ok(Rule::Uppercase),
+            Some(rule) => Err(Error::new(rule.span(), "expected camelCase or UPPERCASE")),
+        }
+    }
+
+    fn apply(self, field: &str) -> String {
+        match self {
+            Rule::Verbatim => field.to_string(),
+            Rule::CamelCase => {
+                let mut name = String::with_capacity(field.len());
+                let mut capitalize = false;
+                for c in field.chars() {
+                    match c {
+                        '_' => capitalize = true,
+                        c if capitalize => {
+                            name.push(c.to_ascii_uppercase());
+                            capitalize = false;
+                        }
+                        c => name.push(c),
+                    }
+                }
+                name
+            }
+            Rule::Uppercase => field.to_ascii_uppercase(),
+        }
+    }
+}
+
+fn expand(rule: Option<Ident>, input: &mut DeriveInput) -> syn::Result<()> {
+    let rule = Rule::parse(rule)?;
+    let fields = match &mut input.data {
+        Data::Struct(data) => match &mut data.fields {
+            Fields::Named(fields) => &mut fields.named,
+            _ => return Err(Error::new_spanned(&input.ident, "expected named fields")),
+        },
+        _ => return Err(Error::new_spanned(&input.ident, "expected a struct")),
+    };
+    for field in fields {
+        let mut wire = None;
+        let mut unnamed = false; // flattened or skipped
+        let mut attrs = Vec::with_capacity(field.attrs.len());
+        for attr in field.attrs.drain(..) {
+            if attr.path().is_ident("wire") {
+                wire = Some(wire_name(&attr)?);
+                continue;
+            }
+            for word in serde_words(&attr) {
+                match word.as_str() {
+                    "flatten" | "skip" => unnamed = true,
+                    "rename" => {
+                        return Err(Error::new_spanned(
+                            &attr,
+                            "set the Binance name with #[wire = \"...\"]",
+                        ))
+                    }
+                    _ => {}
+                }
+            }
+            attrs.push(attr);
+        }
+        field.attrs = attrs;
+        if unnamed {
+            continue;
+        }
+        let name = field.ident.as_ref().unwrap().unraw().to_string();
+        let wire = wire.unwrap_or_else(|| rule.apply(&name));
+        if wire == name {
+            continue;
+        }
+        field.attrs.push(parse_quote! {
+            #[serde(rename(deserialize = #wire), alias = #name)]
+        });
+        field.attrs.push(parse_quote! {
+            #[cfg_attr(not(feature = "readable_names"), serde(rename(serialize = #wire)))]
+        });
+    }
+    Ok(())
+}
+
+fn wire_name(attr: &Attribute) -> syn::Result<String> {
+    match &attr.meta {
+        Meta::NameValue(meta) => match &meta.value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(name),
+                ..
+            }) => Ok(name.value()),
+            value => Err(Error::new_spanned(value, "expected a string")),
+        },
+        meta => Err(Error::new_spanned(meta, "expected #[wire = \"...\"]")),
+    }
+}
+
+// The top level words of a serde attribute, like `default` or `flatten`
+fn serde_words(attr: &Attribute) -> Vec<String> {
+    match &attr.meta {
+        Meta::List(list) if list.path.is_ident("serde") => list
+            .tokens
+            .clone()
+            .into_iter()
+            .filter_map(|token| match token {
+                TokenTree::Ident(word) => Some(word.to_string()),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand() {
+        let mut input: DeriveInput = parse_quote! {
+            struct Event {
+                #[wire = "E"]
+                event_time: u64,
+                #[serde(default)]
+                last_update_id: u64,
+                r#type: String,
+                #[serde(flatten)]
+                extra: Extra,
+            }
+        };
+        expand(Some(parse_quote!(camelCase)), &mut input).unwrap();
+        let expected: DeriveInput = parse_quote! {
+            struct Event {
+                #[serde(rename(deserialize = "E"), alias = "event_time")]
+                #[cfg_attr(not(feature = "readable_names"), serde(rename(serialize = "E")))]
+                event_time: u64,
+                #[serde(default)]
+                #[serde(rename(deserialize = "lastUpdateId"), alias = "last_update_id")]
+                #[cfg_attr(
+                    not(feature = "readable_names"),
+                    serde(rename(serialize = "lastUpdateId"))
+                )]
+                last_update_id: u64,
+                r#type: String,
+                #[serde(flatten)]
+                extra: Extra,
+            }
+        };
+        assert_eq!(quote!(#input).to_string(), quote!(#expected).to_string());
+
+        assert_eq!(Rule::Uppercase.apply("long"), "LONG");
+        let mut input: DeriveInput = parse_quote! {
+            struct Event {
+                #[serde(rename = "E")]
+                event_time: u64,
+            }
+        };
+        assert!(expand(None, &mut input).is_err());
+    }
+}
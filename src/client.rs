@@ -1,4 +1,6 @@
-use reqwest::Client;
+use crate::errors::UnknownEnvironment;
+use reqwest::{Client, Url};
+use std::str::FromStr;
 
 #[derive(Debug, Clone)]
 pub struct Spot;
@@ -6,46 +8,226 @@ pub struct Spot;
 pub struct Usdm;
 #[derive(Debug, Clone)]
 pub struct Coinm;
+#[derive(Debug, Clone)]
+pub struct Margin;
+#[derive(Debug, Clone)]
+pub struct PortfolioMargin;
+#[derive(Debug, Clone)]
+pub struct Options;
+
+// What each market supports, so that unsupported calls don't compile
+pub trait RestMarket {}
+pub trait StreamMarket {}
+pub trait WsApiMarket {}
+
+impl RestMarket for Spot {}
+impl StreamMarket for Spot {}
+impl WsApiMarket for Spot {}
+
+impl RestMarket for Usdm {}
+impl StreamMarket for Usdm {}
+impl WsApiMarket for Usdm {}
+
+impl RestMarket for Coinm {}
+impl StreamMarket for Coinm {}
+
+impl RestMarket for Margin {}
+impl StreamMarket for Margin {}
+
+impl RestMarket for PortfolioMargin {}
+impl StreamMarket for PortfolioMargin {}
+
+impl RestMarket for Options {}
+impl StreamMarket for Options {}
 
 #[derive(Debug, Clone)]
 pub struct ClientConfig<T> {
     pub rest_base_url: String,
     pub websocket_base_url: String,
     pub ws_api_base_url: String,
+    pub paper: bool, // only query production
     _marker: std::marker::PhantomData<T>,
 }
 
-impl Default for ClientConfig<Spot> {
-    fn default() -> Self {
-        Self {
-            rest_base_url: "https://api.binance.com".to_string(),
-            websocket_base_url: "wss://stream.binance.com:9443".to_string(),
-            ws_api_base_url: "wss://ws-api.binance.com:443/ws-api/v3".to_string(),
-            _marker: std::marker::PhantomData,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Environment {
+    Production,
+    Testnet,
+    Demo,
+}
+
+impl FromStr for Environment {
+    type Err = UnknownEnvironment;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "production" | "prod" | "live" => Ok(Environment::Production),
+            "testnet" | "test" => Ok(Environment::Testnet),
+            "demo" => Ok(Environment::Demo),
+            _ => Err(UnknownEnvironment(s.to_string())),
         }
     }
 }
 
-impl Default for ClientConfig<Usdm> {
-    fn default() -> Self {
-        Self {
-            rest_base_url: "https://fapi.binance.com".to_string(),
-            websocket_base_url: "wss://fstream.binance.com".to_string(),
-            ws_api_base_url: "wss://ws-fapi.binance.com/ws-fapi/v1".to_string(),
-            _marker: std::marker::PhantomData,
+#[derive(Debug, Clone, Copy)]
+pub struct BaseUrls {
+    pub rest: &'static str,
+    pub websocket: &'static str,
+    pub ws_api: &'static str, // empty when the market has no WS API
+}
+
+pub trait MarketUrls {
+    fn base_urls(environment: Environment) -> BaseUrls;
+}
+
+impl MarketUrls for Spot {
+    fn base_urls(environment: Environment) -> BaseUrls {
+        match environment {
+            Environment::Production => BaseUrls {
+                rest: "https://api.binance.com",
+                websocket: "wss://stream.binance.com:9443",
+                ws_api: "wss://ws-api.binance.com:443/ws-api/v3",
+            },
+            Environment::Testnet => BaseUrls {
+                rest: "https://testnet.binance.vision",
+                websocket: "wss://stream.testnet.binance.vision",
+                ws_api: "wss://ws-api.testnet.binance.vision/ws-api/v3",
+            },
+            Environment::Demo => BaseUrls {
+                rest: "https://demo-api.binance.com",
+                websocket: "wss://demo-stream.binance.com",
+                ws_api: "wss://demo-ws-api.binance.com/ws-api/v3",
+            },
         }
     }
 }
 
-impl Default for ClientConfig<Coinm> {
-    fn default() -> Self {
+impl MarketUrls for Usdm {
+    fn base_urls(environment: Environment) -> BaseUrls {
+        match environment {
+            Environment::Production => BaseUrls {
+                rest: "https://fapi.binance.com",
+                websocket: "wss://fstream.binance.com",
+                ws_api: "wss://ws-fapi.binance.com/ws-fapi/v1",
+            },
+            Environment::Testnet => BaseUrls {
+                rest: "https://testnet.binancefuture.com",
+                websocket: "wss://stream.binancefuture.com",
+                ws_api: "wss://testnet.binancefuture.com/ws-fapi/v1",
+            },
+            Environment::Demo => BaseUrls {
+                rest: "https://demo-fapi.binance.com",
+                websocket: "wss://fstream.binancefuture.com",
+                ws_api: "wss://testnet.binancefuture.com/ws-fapi/v1",
+            },
+        }
+    }
+}
+
+impl MarketUrls for Coinm {
+    fn base_urls(environment: Environment) -> BaseUrls {
+        match environment {
+            Environment::Production => BaseUrls {
+                rest: "https://dapi.binance.com",
+                websocket: "wss://dstream.binance.com",
+                ws_api: "",
+            },
+            Environment::Testnet => BaseUrls {
+                rest: "https://testnet.binancefuture.com",
+                websocket: "wss://dstream.binancefuture.com",
+                ws_api: "",
+            },
+            Environment::Demo => BaseUrls {
+                rest: "https://demo-dapi.binance.com",
+                websocket: "wss://dstream.binancefuture.com",
+                ws_api: "",
+            },
+        }
+    }
+}
+
+// Margin shares the spot hosts, but the spot testnet and demo don't serve the /sapi
+// endpoints: paper clients keep the production hosts, like portfolio margin, and
+// are refused any signed request but GETs there
+impl MarketUrls for Margin {
+    fn base_urls(_environment: Environment) -> BaseUrls {
+        BaseUrls {
+            ws_api: "",
+            ..Spot::base_urls(Environment::Production)
+        }
+    }
+}
+
+// There is no portfolio margin testnet: paper clients keep the production hosts, where
+// signed requests other than GETs are refused, so they can only query
+impl MarketUrls for PortfolioMargin {
+    fn base_urls(_environment: Environment) -> BaseUrls {
+        BaseUrls {
+            rest: "https://papi.binance.com",
+            websocket: "wss://fstream.binance.com/pm",
+            ws_api: "",
+        }
+    }
+}
+
+// Same as portfolio margin, options have no testnet
+impl MarketUrls for Options {
+    fn base_urls(_environment: Environment) -> BaseUrls {
+        BaseUrls {
+            rest: "https://eapi.binance.com",
+            websocket: "wss://nbstream.binance.com/eoptions",
+            ws_api: "",
+        }
+    }
+}
+
+impl<T: MarketUrls> ClientConfig<T> {
+    pub fn environment(environment: Environment) -> Self {
+        let urls = T::base_urls(environment);
         Self {
-            rest_base_url: "https://dapi.binance.com".to_string(),
-            websocket_base_url: "wss://dstream.binance.com".to_string(),
-            ws_api_base_url: "".to_string(),
+            rest_base_url: urls.rest.to_string(),
+            websocket_base_url: urls.websocket.to_string(),
+            ws_api_base_url: urls.ws_api.to_string(),
+            paper: environment != Environment::Production,
             _marker: std::marker::PhantomData,
         }
     }
+
+    // Reads BINANCE_ENVIRONMENT, production when unset
+    pub fn from_env() -> Result<Self, UnknownEnvironment> {
+        match std::env::var("BINANCE_ENVIRONMENT") {
+            Ok(value) => Ok(Self::environment(value.parse()?)),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub(crate) fn targets_production_rest(&self) -> bool {
+        targets_production::<T>(&self.rest_base_url, |urls| urls.rest)
+    }
+
+    pub(crate) fn targets_production_ws_api(&self) -> bool {
+        targets_production::<T>(&self.ws_api_base_url, |urls| urls.ws_api)
+    }
+}
+
+// Only the hosts of the testnet and demo presets are known not to be production. Any
+// other host, or a URL that doesn't parse, is taken for production.
+fn targets_production<T: MarketUrls>(url: &str, preset: fn(&BaseUrls) -> &str) -> bool {
+    let host = |url: &str| Some(Url::parse(url).ok()?.host_str()?.to_string());
+    let Some(target) = host(url) else {
+        return true;
+    };
+    let production = host(preset(&T::base_urls(Environment::Production)));
+    ![Environment::Testnet, Environment::Demo]
+        .into_iter()
+        .filter_map(|environment| host(preset(&T::base_urls(environment))))
+        .any(|paper| paper == target && Some(&paper) != production.as_ref())
+}
+
+impl<T: MarketUrls> Default for ClientConfig<T> {
+    fn default() -> Self {
+        Self::environment(Environment::Production)
+    }
 }
 
 impl<T> ClientConfig<T> {
@@ -61,6 +243,10 @@ impl<T> ClientConfig<T> {
         self.ws_api_base_url = ws_api_base_url;
         self
     }
+    pub fn with_paper(mut self, paper: bool) -> Self {
+        self.paper = paper;
+        self
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -96,6 +282,33 @@ impl Default for BinanceClient<Coinm> {
     }
 }
 
+impl Default for BinanceClient<Margin> {
+    fn default() -> Self {
+        Self {
+            client: Client::default(),
+            config: ClientConfig::default(),
+        }
+    }
+}
+
+impl Default for BinanceClient<PortfolioMargin> {
+    fn default() -> Self {
+        Self {
+            client: Client::default(),
+            config: ClientConfig::default(),
+        }
+    }
+}
+
+impl Default for BinanceClient<Options> {
+    fn default() -> Self {
+        Self {
+            client: Client::default(),
+            config: ClientConfig::default(),
+        }
+    }
+}
+
 impl BinanceClient<Spot> {
     pub fn spot() -> Self {
         Self::default()
@@ -114,9 +327,71 @@ impl BinanceClient<Coinm> {
     }
 }
 
+impl BinanceClient<Margin> {
+    pub fn margin() -> Self {
+        Self::default()
+    }
+}
+
+impl BinanceClient<PortfolioMargin> {
+    pub fn portfolio_margin() -> Self {
+        Self::default()
+    }
+}
+
+impl BinanceClient<Options> {
+    pub fn options() -> Self {
+        Self::default()
+    }
+}
+
 impl<T> BinanceClient<T> {
     pub fn with_config(mut self, config: ClientConfig<T>) -> Self {
         self.config = config;
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_targets_production() {
+        let spot = |rest: &str| {
+            ClientConfig::<Spot>::environment(Environment::Testnet)
+                .with_rest_base_url(rest.to_string())
+                .targets_production_rest()
+        };
+        assert!(!spot("https://testnet.binance.vision"));
+        assert!(!spot("https://testnet.binance.vision/"));
+        assert!(!spot("https://demo-api.binance.com"));
+        assert!(spot("https://api.binance.com"));
+        assert!(spot("https://api.binance.com/"));
+        assert!(spot("https://api1.binance.com"));
+        assert!(spot("https://api4.binance.com"));
+        assert!(spot("https://api-gcp.binance.com"));
+        assert!(spot("https://testnet.binance.vision.example.com"));
+        assert!(spot("testnet.binance.vision"));
+        assert!(spot(""));
+
+        let ws_api = |ws_api: &str| {
+            ClientConfig::<Usdm>::environment(Environment::Demo)
+                .with_ws_api_base_url(ws_api.to_string())
+                .targets_production_ws_api()
+        };
+        assert!(!ws_api("wss://testnet.binancefuture.com/ws-fapi/v1"));
+        assert!(ws_api("wss://ws-fapi.binance.com/ws-fapi/v1"));
+        assert!(ws_api("wss://ws-api.binance.com:9443/ws-api/v3"));
+
+        // No testnet, the paper presets keep the production hosts
+        assert!(ClientConfig::<Margin>::environment(Environment::Testnet).targets_production_rest());
+        assert!(
+            ClientConfig::<PortfolioMargin>::environment(Environment::Demo)
+                .targets_production_rest()
+        );
+        assert!(
+            ClientConfig::<Options>::environment(Environment::Testnet).targets_production_rest()
+        );
+    }
+}
@@ -0,0 +1,327 @@
+use crate::rounding::Rounding;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, ops::Deref, str::FromStr};
+
+// Numeric type of price, quantity, balance and PnL fields. Binance sends them
+// as strings, kept as is unless the `rust_decimal` or `f64` feature is enabled.
+// `rust_decimal` takes precedence when both are.
+#[cfg(feature = "rust_decimal")]
+pub type Decimal = rust_decimal::Decimal;
+#[cfg(all(feature = "f64", not(feature = "rust_decimal")))]
+pub type Decimal = Float;
+#[cfg(not(any(feature = "rust_decimal", feature = "f64")))]
+pub type Decimal = String;
+
+// Same as `Decimal` on request fields, which take a borrowed or owned string by
+// default
+#[cfg(any(feature = "rust_decimal", feature = "f64"))]
+pub type DecimalParam<'a> = Decimal;
+#[cfg(not(any(feature = "rust_decimal", feature = "f64")))]
+pub type DecimalParam<'a> = std::borrow::Cow<'a, str>;
+
+// A request value from its decimal string, under any of the features. None if
+// it doesn't parse, strings are taken as they are.
+#[cfg(any(feature = "rust_decimal", feature = "f64"))]
+pub fn decimal_param(value: &str) -> Option<DecimalParam<'_>> {
+    value.parse().ok()
+}
+
+#[cfg(not(any(feature = "rust_decimal", feature = "f64")))]
+pub fn decimal_param(value: &str) -> Option<DecimalParam<'_>> {
+    Some(value.into())
+}
+
+// f64 that goes over the wire as a string. Rust never formats floats with an
+// exponent, and prints the shortest representation that parses back exactly.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Float(pub f64);
+
+impl Deref for Float {
+    type Target = f64;
+    fn deref(&self) -> &f64 {
+        &self.0
+    }
+}
+
+impl From<f64> for Float {
+    fn from(value: f64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Float> for f64 {
+    fn from(value: Float) -> Self {
+        value.0
+    }
+}
+
+impl FromStr for Float {
+    type Err = std::num::ParseFloatError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+
+impl fmt::Display for Float {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl Serialize for Float {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Float {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrNumber<'a> {
+            String(&'a str),
+            OwnedString(String),
+            Number(f64),
+        }
+
+        match StringOrNumber::deserialize(deserializer)? {
+            StringOrNumber::String(s) => s.parse().map_err(serde::de::Error::custom),
+            StringOrNumber::OwnedString(s) => s.parse().map_err(serde::de::Error::custom),
+            StringOrNumber::Number(n) => Ok(Self(n)),
+        }
+    }
+}
+
+// Exact fixed point number used to check and round values against the symbol
+// filters, whatever the `Decimal` representation is
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Fixed {
+    mantissa: i128,
+    scale: u32,
+}
+
+impl Fixed {
+    // Plain decimal notation only, which is what Binance and every `Decimal`
+    // flavour produce
+    pub(crate) fn parse(s: &str) -> Option<Fixed> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+        if int.is_empty() && frac.is_empty() {
+            return None;
+        }
+        let mut mantissa: i128 = 0;
+        for c in int.chars().chain(frac.chars()) {
+            let digit = c.to_digit(10)? as i128;
+            mantissa = mantissa.checked_mul(10)?.checked_add(digit)?;
+        }
+        let fixed = Fixed {
+            mantissa: if negative { -mantissa } else { mantissa },
+            scale: frac.len() as u32,
+        };
+        Some(fixed.normalize())
+    }
+
+    pub(crate) fn from_display(value: impl fmt::Display) -> Option<Fixed> {
+        Fixed::parse(&value.to_string())
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+
+    fn normalize(mut self) -> Fixed {
+        while self.scale > 0 && self.mantissa % 10 == 0 {
+            self.mantissa /= 10;
+            self.scale -= 1;
+        }
+        self
+    }
+
+    fn rescale(&self, scale: u32) -> Option<i128> {
+        self.mantissa
+            .checked_mul(10i128.checked_pow(scale.checked_sub(self.scale)?)?)
+    }
+
+    // Both mantissas on the same scale
+    fn align(&self, other: &Fixed) -> Option<(i128, i128, u32)> {
+        let scale = self.scale.max(other.scale);
+        Some((self.rescale(scale)?, other.rescale(scale)?, scale))
+    }
+
+    pub(crate) fn checked_mul(&self, other: &Fixed) -> Option<Fixed> {
+        let fixed = Fixed {
+            mantissa: self.mantissa.checked_mul(other.mantissa)?,
+            scale: self.scale + other.scale,
+        };
+        Some(fixed.normalize())
+    }
+
+    pub(crate) fn is_multiple_of(&self, step: &Fixed) -> Option<bool> {
+        let (value, step, _) = self.align(step)?;
+        Some(step == 0 || value % step == 0)
+    }
+
+    // Ties round up with `Rounding::Nearest`
+    pub(crate) fn round_to(&self, step: &Fixed, rounding: Rounding) -> Option<Fixed> {
+        let (value, step, scale) = self.align(step)?;
+        if step == 0 {
+            return Some(*self);
+        }
+        let (quotient, remainder) = (value.div_euclid(step), value.rem_euclid(step));
+        let steps = match rounding {
+            Rounding::Down => quotient,
+            Rounding::Up if remainder > 0 => quotient + 1,
+            Rounding::Up => quotient,
+            Rounding::Nearest if remainder >= step - remainder => quotient + 1,
+            Rounding::Nearest => quotient,
+        };
+        let fixed = Fixed {
+            mantissa: steps.checked_mul(step)?,
+            scale,
+        };
+        Some(fixed.normalize())
+    }
+
+    // Smallest multiple of `step` that reaches `target` once multiplied by `self`
+    pub(crate) fn min_multiple_reaching(&self, step: &Fixed, target: &Fixed) -> Option<Fixed> {
+        let (target, unit, _) = target.align(&step.checked_mul(self)?)?;
+        if unit <= 0 {
+            return None;
+        }
+        let steps = (target.max(0) + unit - 1) / unit;
+        let fixed = Fixed {
+            mantissa: step.mantissa.checked_mul(steps)?,
+            scale: step.scale,
+        };
+        Some(fixed.normalize())
+    }
+
+    // 10^-scale, the step of a value with `scale` decimals
+    pub(crate) fn unit(scale: u32) -> Fixed {
+        Fixed { mantissa: 1, scale }
+    }
+
+    pub(crate) fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    // Pads with zeros up to `scale` decimals, the value must not have more
+    pub(crate) fn format_with_scale(self, scale: u32) -> Option<String> {
+        let mantissa = self.rescale(scale)?;
+        Some(Fixed { mantissa, scale }.to_string())
+    }
+}
+
+// By value like `Ord`, 1.50 equals 1.5
+impl PartialEq for Fixed {
+    fn eq(&self, other: &Fixed) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for Fixed {}
+
+impl PartialOrd for Fixed {
+    fn partial_cmp(&self, other: &Fixed) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Fixed {
+    fn cmp(&self, other: &Fixed) -> std::cmp::Ordering {
+        match self.align(other) {
+            Some((a, b, _)) => a.cmp(&b),
+            // Out of range once aligned, compares the sign, then the integer
+            // digits and then the fraction digits
+            None => {
+                let magnitude = |fixed: &Fixed| {
+                    let digits = fixed.to_string();
+                    let digits = digits.trim_start_matches('-');
+                    let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+                    (
+                        int.len(),
+                        int.to_string(),
+                        frac.trim_end_matches('0').to_string(),
+                    )
+                };
+                let ordering = magnitude(self).cmp(&magnitude(other));
+                self.mantissa
+                    .signum()
+                    .cmp(&other.mantissa.signum())
+                    .then(if self.mantissa < 0 {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    })
+            }
+        }
+    }
+}
+
+// Doesn't normalize, so that `format_with_scale` keeps its trailing zeros
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let scale = self.scale as usize;
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        if scale == 0 {
+            return write!(f, "{}{}", sign, digits);
+        }
+        let digits = format!("{:0>width$}", digits, width = scale + 1);
+        let (int, frac) = digits.split_at(digits.len() - scale);
+        write!(f, "{}{}.{}", sign, int, frac)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_float_round_trip() {
+        let float: Float = serde_json::from_str("\"0.00000010\"").unwrap();
+        assert_eq!(float, Float(0.0000001));
+        assert_eq!(serde_json::to_string(&float).unwrap(), "\"0.0000001\"");
+        let float: Float = serde_json::from_str("12345678.5").unwrap();
+        assert_eq!(serde_json::to_string(&float).unwrap(), "\"12345678.5\"");
+    }
+
+    #[test]
+    fn test_fixed_arithmetic() {
+        let price = Fixed::parse("27123.4500").unwrap();
+        let tick = Fixed::parse("0.05").unwrap();
+        assert_eq!(price.to_string(), "27123.45");
+        assert_eq!(price.is_multiple_of(&tick), Some(true));
+        assert_eq!(
+            Fixed::parse("0.0003").unwrap().is_multiple_of(&tick),
+            Some(false)
+        );
+        let notional = price.checked_mul(&Fixed::parse("0.002").unwrap()).unwrap();
+        assert_eq!(notional.to_string(), "54.2469");
+        assert!(Fixed::parse("-1.5") < Fixed::parse("0"));
+        // Too far apart to align in an i128
+        let fixed = |mantissa, scale| Fixed { mantissa, scale };
+        let huge = fixed(i128::MAX, 0);
+        assert!(fixed(15, 40) < huge && fixed(-15, 40) > fixed(-i128::MAX, 0));
+        assert!(fixed(18, 1) > fixed(10i128.pow(38) + 1, 38));
+        assert!(fixed(-18, 1) < fixed(-(10i128.pow(38) + 1), 38));
+        assert!(fixed(-15, 40) > fixed(-2, 39));
+        assert_eq!(fixed(10, 40).cmp(&fixed(1, 39)), std::cmp::Ordering::Equal);
+        assert_eq!(fixed(10, 40), fixed(1, 39));
+        assert_eq!(fixed(150, 2), fixed(15, 1));
+        assert_ne!(fixed(150, 2), fixed(15, 2));
+        assert_eq!(Fixed::parse("1e-7"), None);
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_decimal_round_trip() {
+        let decimal: Decimal = serde_json::from_str("\"0.00000010\"").unwrap();
+        assert_eq!(serde_json::to_string(&decimal).unwrap(), "\"0.00000010\"");
+        let decimal = Decimal::new(1, 7);
+        assert_eq!(serde_json::to_string(&decimal).unwrap(), "\"0.0000001\"");
+    }
+}
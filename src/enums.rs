@@ -0,0 +1,180 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// Every enum keeps an `Unknown` fallback so that a value added by Binance
+// doesn't break deserialization of the whole response or event
+macro_rules! string_enum {
+    ($name:ident { $($variant:ident => $value:literal),+ $(,)? }) => {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)+
+            Unknown(String),
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)+
+                    Self::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => Self::$variant,)+
+                    other => Self::Unknown(other.to_string()),
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                Ok(Self::from(value.as_ref()))
+            }
+        }
+    };
+}
+
+string_enum!(Side {
+    Buy => "BUY",
+    Sell => "SELL",
+});
+
+// Spot and futures types share one enum, the exchange rejects the ones
+// that don't apply to a market
+string_enum!(OrderType {
+    Limit => "LIMIT",
+    Market => "MARKET",
+    StopLoss => "STOP_LOSS",
+    StopLossLimit => "STOP_LOSS_LIMIT",
+    TakeProfit => "TAKE_PROFIT",
+    TakeProfitLimit => "TAKE_PROFIT_LIMIT",
+    LimitMaker => "LIMIT_MAKER",
+    Stop => "STOP",
+    StopMarket => "STOP_MARKET",
+    TakeProfitMarket => "TAKE_PROFIT_MARKET",
+    TrailingStopMarket => "TRAILING_STOP_MARKET",
+    Liquidation => "LIQUIDATION",
+});
+
+string_enum!(TimeInForce {
+    Gtc => "GTC",
+    Ioc => "IOC",
+    Fok => "FOK",
+    Gtx => "GTX", // post only, futures
+    Gtd => "GTD", // futures
+});
+
+string_enum!(OrderStatus {
+    New => "NEW",
+    PartiallyFilled => "PARTIALLY_FILLED",
+    Filled => "FILLED",
+    Canceled => "CANCELED",
+    PendingCancel => "PENDING_CANCEL",
+    Rejected => "REJECTED",
+    Expired => "EXPIRED",
+    ExpiredInMatch => "EXPIRED_IN_MATCH",
+    NewInsurance => "NEW_INSURANCE",
+    NewAdl => "NEW_ADL",
+});
+
+string_enum!(ExecutionType {
+    New => "NEW",
+    Canceled => "CANCELED",
+    Replaced => "REPLACED",
+    Rejected => "REJECTED",
+    Trade => "TRADE",
+    Expired => "EXPIRED",
+    TradePrevention => "TRADE_PREVENTION",
+    Calculated => "CALCULATED",
+    Amendment => "AMENDMENT",
+});
+
+string_enum!(PositionSide {
+    Both => "BOTH",
+    Long => "LONG",
+    Short => "SHORT",
+});
+
+string_enum!(WorkingType {
+    MarkPrice => "MARK_PRICE",
+    ContractPrice => "CONTRACT_PRICE",
+});
+
+string_enum!(SelfTradePreventionMode {
+    None => "NONE",
+    ExpireTaker => "EXPIRE_TAKER",
+    ExpireMaker => "EXPIRE_MAKER",
+    ExpireBoth => "EXPIRE_BOTH",
+    Decrement => "DECREMENT",
+});
+
+string_enum!(PriceMatch {
+    None => "NONE",
+    Opponent => "OPPONENT",
+    Opponent5 => "OPPONENT_5",
+    Opponent10 => "OPPONENT_10",
+    Opponent20 => "OPPONENT_20",
+    Queue => "QUEUE",
+    Queue5 => "QUEUE_5",
+    Queue10 => "QUEUE_10",
+    Queue20 => "QUEUE_20",
+});
+
+string_enum!(RateLimitType {
+    RequestWeight => "REQUEST_WEIGHT",
+    Orders => "ORDERS",
+    RawRequests => "RAW_REQUESTS",
+});
+
+string_enum!(RateLimitInterval {
+    Second => "SECOND",
+    Minute => "MINUTE",
+    Day => "DAY",
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_fallback() {
+        let known: TimeInForce = serde_json::from_str("\"GTX\"").unwrap();
+        assert_eq!(known, TimeInForce::Gtx);
+        let unknown: OrderStatus = serde_json::from_str("\"SOMETHING_NEW\"").unwrap();
+        assert_eq!(unknown, OrderStatus::Unknown("SOMETHING_NEW".to_string()));
+        assert_eq!(
+            serde_json::to_string(&unknown).unwrap(),
+            "\"SOMETHING_NEW\""
+        );
+
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Params {
+            side: Side,
+            time_in_force: Option<TimeInForce>,
+        }
+        let params = Params {
+            side: Side::Sell,
+            time_in_force: Some(TimeInForce::Gtd),
+        };
+        assert_eq!(
+            serde_qs::to_string(&params).unwrap(),
+            "side=SELL&timeInForce=GTD"
+        );
+    }
+}
@@ -3,7 +3,7 @@ use reqwest::{
     header::{HeaderMap, InvalidHeaderValue},
     StatusCode,
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tokio_tungstenite::tungstenite;
 
@@ -15,7 +15,7 @@ pub struct ResponseError {
     pub content: ContentError,
 }
 
-#[derive(Deserialize, Debug, Clone, Error)]
+#[derive(Deserialize, Serialize, Debug, Clone, Error)]
 #[error("code: {} - msg: {}", code, msg)]
 pub struct ContentError {
     pub code: i64,
@@ -32,10 +32,22 @@ pub enum RequestError {
     Response(#[from] ResponseError),
     #[error(transparent)]
     Request(#[from] reqwest::Error),
+    #[error("Refusing to send a request that isn't a query to production from a paper client")]
+    PaperMode,
 }
 
 #[derive(Debug, Error)]
 pub enum WsConnectionError {
     #[error("Ws connection error: {0}")]
     Connection(#[from] Box<tungstenite::Error>),
+    #[error("Refusing to send an order request to the production WS API from a paper client")]
+    PaperMode,
+    #[error("Ws connection closed")]
+    Closed,
+    #[error("A request with id {0} is already waiting for its response")]
+    DuplicateId(u64),
 }
+
+#[derive(Debug, Clone, Error)]
+#[error("Unknown environment: {0}")]
+pub struct UnknownEnvironment(pub String);
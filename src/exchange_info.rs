@@ -0,0 +1,500 @@
+use crate::{
+    client::{BinanceClient, Coinm, MarketUrls, RestMarket, Spot, StreamMarket, Usdm},
+    errors::{RequestError, WsConnectionError},
+    rest::{coinm, spot, usdm, PublicRequest},
+    websocket::{self, usdm::ContractInfoEvent},
+};
+use futures_util::stream::{self, Stream, StreamExt};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+use tokio::{sync::broadcast, task::JoinHandle};
+
+// Error code of requests naming a symbol the exchange doesn't know, usually
+// one that was listed after the last refresh
+pub const INVALID_SYMBOL: i64 = -1121;
+
+pub trait ListedMarket: Clone + Send + Sync + 'static {
+    fn symbol(&self) -> &str;
+    fn pair(&self) -> Option<&str>; // futures only
+    fn base_asset(&self) -> &str;
+    fn quote_asset(&self) -> &str;
+    fn status(&self) -> &str;
+    fn set_status(&mut self, status: &str);
+}
+
+pub trait ExchangeInfoMarket: RestMarket + MarketUrls + Clone + Send + Sync + 'static {
+    type Request: PublicRequest<Self> + Send + Sync;
+    type Market: ListedMarket;
+    fn exchange_info_request() -> Self::Request;
+    fn markets(response: <Self::Request as PublicRequest<Self>>::Response) -> Vec<Self::Market>;
+}
+
+// `previous` is None for a new listing and `current` None for a delisting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusChange {
+    pub symbol: String,
+    pub previous: Option<String>,
+    pub current: Option<String>,
+}
+
+#[derive(Debug)]
+struct Index<M> {
+    loaded: bool,
+    markets: HashMap<String, M>,
+    by_pair: HashMap<String, Vec<String>>,
+    by_base_asset: HashMap<String, Vec<String>>,
+    by_quote_asset: HashMap<String, Vec<String>>,
+    // Listings seen on the contract info stream before a refresh picks them
+    // up, by symbol, with their last published status
+    announced: HashMap<String, String>,
+}
+
+impl<M: ListedMarket> Index<M> {
+    fn new(markets: Vec<M>) -> Self {
+        let mut index = Index {
+            loaded: true,
+            markets: HashMap::new(),
+            by_pair: HashMap::new(),
+            by_base_asset: HashMap::new(),
+            by_quote_asset: HashMap::new(),
+            announced: HashMap::new(),
+        };
+        for market in markets {
+            let symbol = market.symbol().to_string();
+            if let Some(pair) = market.pair() {
+                index
+                    .by_pair
+                    .entry(pair.to_string())
+                    .or_default()
+                    .push(symbol.clone());
+            }
+            index
+                .by_base_asset
+                .entry(market.base_asset().to_string())
+                .or_default()
+                .push(symbol.clone());
+            index
+                .by_quote_asset
+                .entry(market.quote_asset().to_string())
+                .or_default()
+                .push(symbol.clone());
+            index.markets.insert(symbol, market);
+        }
+        index
+    }
+
+    fn lookup(&self, symbols: Option<&Vec<String>>) -> Vec<M> {
+        symbols
+            .into_iter()
+            .flatten()
+            .filter_map(|symbol| self.markets.get(symbol).cloned())
+            .collect()
+    }
+}
+
+impl<M> Default for Index<M> {
+    fn default() -> Self {
+        Index {
+            loaded: false,
+            markets: HashMap::new(),
+            by_pair: HashMap::new(),
+            by_base_asset: HashMap::new(),
+            by_quote_asset: HashMap::new(),
+            announced: HashMap::new(),
+        }
+    }
+}
+
+// Cheap to clone, clones share the same markets and status changes
+#[derive(Debug)]
+pub struct ExchangeInfoCache<T: ExchangeInfoMarket> {
+    client: BinanceClient<T>,
+    index: Arc<RwLock<Index<T::Market>>>,
+    changes: broadcast::Sender<StatusChange>,
+}
+
+impl<T: ExchangeInfoMarket> Clone for ExchangeInfoCache<T> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            index: self.index.clone(),
+            changes: self.changes.clone(),
+        }
+    }
+}
+
+impl<T: ExchangeInfoMarket> ExchangeInfoCache<T> {
+    // Empty until the first `refresh`
+    pub fn new(client: BinanceClient<T>) -> Self {
+        Self {
+            client,
+            index: Arc::new(RwLock::new(Index::default())),
+            changes: broadcast::channel(1024).0,
+        }
+    }
+
+    pub async fn refresh(&self) -> Result<(), RequestError> {
+        let response = self.client.request(&T::exchange_info_request()).await?;
+        self.replace(T::markets(response.content));
+        Ok(())
+    }
+
+    // Refreshes if `error` is an unknown symbol one, returns whether it did
+    pub async fn refresh_on_invalid_symbol(
+        &self,
+        error: &RequestError,
+    ) -> Result<bool, RequestError> {
+        match error {
+            RequestError::Response(e) if e.content.code == INVALID_SYMBOL => {
+                self.refresh().await?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    // Failed refreshes are handed to `on_error` and retried on the next tick
+    pub fn spawn_refresh<F>(&self, period: Duration, mut on_error: F) -> JoinHandle<()>
+    where
+        F: FnMut(RequestError) + Send + 'static,
+    {
+        let cache = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            loop {
+                interval.tick().await;
+                if let Err(error) = cache.refresh().await {
+                    on_error(error);
+                }
+            }
+        })
+    }
+
+    pub fn market(&self, symbol: &str) -> Option<T::Market> {
+        self.index.read().unwrap().markets.get(symbol).cloned()
+    }
+
+    pub fn markets_by_pair(&self, pair: &str) -> Vec<T::Market> {
+        let index = self.index.read().unwrap();
+        index.lookup(index.by_pair.get(pair))
+    }
+
+    pub fn markets_by_base_asset(&self, asset: &str) -> Vec<T::Market> {
+        let index = self.index.read().unwrap();
+        index.lookup(index.by_base_asset.get(asset))
+    }
+
+    pub fn markets_by_quote_asset(&self, asset: &str) -> Vec<T::Market> {
+        let index = self.index.read().unwrap();
+        index.lookup(index.by_quote_asset.get(asset))
+    }
+
+    // Changes published after the call, a subscriber too slow to keep up
+    // misses the oldest ones
+    pub fn status_changes(&self) -> impl Stream<Item = StatusChange> {
+        stream::unfold(self.changes.subscribe(), |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(change) => return Some((change, receiver)),
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        })
+    }
+
+    // Diffs under the write lock so concurrent refreshes and contract info
+    // updates publish each change once and in order
+    fn replace(&self, markets: Vec<T::Market>) {
+        let mut index = self.index.write().unwrap();
+        let mut old = std::mem::replace(&mut *index, Index::new(markets));
+        if old.loaded {
+            for (symbol, market) in &index.markets {
+                let previous = match old.markets.get(symbol) {
+                    Some(market) => Some(market.status()),
+                    None => old.announced.get(symbol).map(String::as_str),
+                };
+                if previous != Some(market.status()) {
+                    self.publish(symbol, previous, Some(market.status()));
+                }
+            }
+            for (symbol, market) in &old.markets {
+                if !index.markets.contains_key(symbol) {
+                    self.publish(symbol, Some(market.status()), None);
+                }
+            }
+        }
+        old.announced
+            .retain(|symbol, _| !index.markets.contains_key(symbol));
+        index.announced = old.announced;
+    }
+
+    fn apply_contract_info(&self, event: &ContractInfoEvent) {
+        let mut index = self.index.write().unwrap();
+        match index.markets.get_mut(&event.symbol) {
+            Some(market) if market.status() != event.contract_status => {
+                let previous = market.status().to_string();
+                market.set_status(&event.contract_status);
+                self.publish(&event.symbol, Some(&previous), Some(&event.contract_status));
+            }
+            Some(_) => {}
+            // Picked up with all its details on the next refresh, which then
+            // only publishes if the status changed in between
+            None => {
+                let previous = index
+                    .announced
+                    .insert(event.symbol.clone(), event.contract_status.clone());
+                if previous.as_ref() != Some(&event.contract_status) {
+                    self.publish(
+                        &event.symbol,
+                        previous.as_deref(),
+                        Some(&event.contract_status),
+                    );
+                }
+            }
+        }
+    }
+
+    fn publish(&self, symbol: &str, previous: Option<&str>, current: Option<&str>) {
+        // No subscriber isn't an error
+        let _ = self.changes.send(StatusChange {
+            symbol: symbol.to_string(),
+            previous: previous.map(str::to_string),
+            current: current.map(str::to_string),
+        });
+    }
+}
+
+impl<T: ExchangeInfoMarket + StreamMarket> ExchangeInfoCache<T> {
+    async fn follow<S>(&self, topic: &S) -> Result<(), WsConnectionError>
+    where
+        S: websocket::StreamTopic<T, Event = ContractInfoEvent>,
+    {
+        let mut events = self.client.connect_stream(topic).await?.content;
+        while let Some(event) = events.next().await {
+            self.apply_contract_info(&event);
+        }
+        Ok(())
+    }
+}
+
+impl ExchangeInfoCache<Usdm> {
+    // Applies contract status updates as they come, until the stream closes
+    pub async fn follow_contract_info(&self) -> Result<(), WsConnectionError> {
+        self.follow(&websocket::usdm::ContractInfoStream).await
+    }
+}
+
+impl ExchangeInfoCache<Coinm> {
+    // Applies contract status updates as they come, until the stream closes
+    pub async fn follow_contract_info(&self) -> Result<(), WsConnectionError> {
+        self.follow(&websocket::coinm::ContractInfoStream).await
+    }
+}
+
+impl ExchangeInfoMarket for Spot {
+    type Request = spot::ExchangeInfoRequest<'static>;
+    type Market = spot::Market;
+    fn exchange_info_request() -> Self::Request {
+        spot::ExchangeInfoRequest {
+            symbol: None,
+            symbols: None,
+            permissions: None,
+            show_permission_sets: None,
+            symbol_status: None,
+        }
+    }
+    fn markets(response: spot::ExchangeInfoResponse) -> Vec<spot::Market> {
+        response.symbols
+    }
+}
+
+impl ExchangeInfoMarket for Usdm {
+    type Request = usdm::ExchangeInfoRequest;
+    type Market = usdm::Market;
+    fn exchange_info_request() -> Self::Request {
+        usdm::ExchangeInfoRequest
+    }
+    fn markets(response: usdm::ExchangeInfoResponse) -> Vec<usdm::Market> {
+        response.symbols
+    }
+}
+
+impl ExchangeInfoMarket for Coinm {
+    type Request = coinm::ExchangeInfoRequest;
+    type Market = coinm::Market;
+    fn exchange_info_request() -> Self::Request {
+        coinm::ExchangeInfoRequest
+    }
+    fn markets(response: coinm::ExchangeInfoResponse) -> Vec<coinm::Market> {
+        response.symbols
+    }
+}
+
+impl ListedMarket for spot::Market {
+    fn symbol(&self) -> &str {
+        &self.symbol
+    }
+    fn pair(&self) -> Option<&str> {
+        None
+    }
+    fn base_asset(&self) -> &str {
+        &self.base_asset
+    }
+    fn quote_asset(&self) -> &str {
+        &self.quote_asset
+    }
+    fn status(&self) -> &str {
+        &self.status
+    }
+    fn set_status(&mut self, status: &str) {
+        self.status = status.to_string();
+    }
+}
+
+impl ListedMarket for usdm::Market {
+    fn symbol(&self) -> &str {
+        &self.symbol
+    }
+    fn pair(&self) -> Option<&str> {
+        Some(&self.pair)
+    }
+    fn base_asset(&self) -> &str {
+        &self.base_asset
+    }
+    fn quote_asset(&self) -> &str {
+        &self.quote_asset
+    }
+    fn status(&self) -> &str {
+        &self.status
+    }
+    fn set_status(&mut self, status: &str) {
+        self.status = status.to_string();
+    }
+}
+
+impl ListedMarket for coinm::Market {
+    fn symbol(&self) -> &str {
+        &self.symbol
+    }
+    fn pair(&self) -> Option<&str> {
+        Some(&self.pair)
+    }
+    fn base_asset(&self) -> &str {
+        &self.base_asset
+    }
+    fn quote_asset(&self) -> &str {
+        &self.quote_asset
+    }
+    fn status(&self) -> &str {
+        &self.contract_status
+    }
+    fn set_status(&mut self, status: &str) {
+        self.contract_status = status.to_string();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::ClientConfig, validation::tests::market};
+
+    #[tokio::test]
+    async fn test_status_changes() {
+        let cache = ExchangeInfoCache::new(BinanceClient::usdm());
+        let mut changes = Box::pin(cache.status_changes());
+
+        let btc = market();
+        let mut eth = market();
+        eth.symbol = "ETHUSDT".to_string();
+        eth.pair = "ETHUSDT".to_string();
+        eth.base_asset = "ETH".to_string();
+        cache.replace(vec![btc.clone()]);
+        assert_eq!(cache.markets_by_quote_asset("USDT").len(), 1);
+
+        eth.status = "PENDING_TRADING".to_string();
+        cache.replace(vec![btc, eth]);
+        assert_eq!(cache.market("ETHUSDT").unwrap().base_asset, "ETH");
+        assert_eq!(cache.markets_by_quote_asset("USDT").len(), 2);
+        assert_eq!(
+            changes.next().await,
+            Some(StatusChange {
+                symbol: "ETHUSDT".to_string(),
+                previous: None,
+                current: Some("PENDING_TRADING".to_string()),
+            })
+        );
+
+        let event: ContractInfoEvent = serde_json::from_str(
+            r#"{"e":"contractInfo","E":1,"s":"BTCUSDT","ps":"BTCUSDT","ct":"PERPETUAL","dt":4133404800000,"ot":1569398400000,"cs":"SETTLING"}"#,
+        )
+        .unwrap();
+        cache.apply_contract_info(&event);
+        assert_eq!(cache.markets_by_pair("BTCUSDT")[0].status, "SETTLING");
+        assert_eq!(
+            changes.next().await,
+            Some(StatusChange {
+                symbol: "BTCUSDT".to_string(),
+                previous: Some("TRADING".to_string()),
+                current: Some("SETTLING".to_string()),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_announced_listing_published_once() {
+        let cache = ExchangeInfoCache::new(BinanceClient::usdm());
+        let mut changes = Box::pin(cache.status_changes());
+        cache.replace(vec![market()]);
+
+        let event = |status: &str| -> ContractInfoEvent {
+            serde_json::from_str(&format!(
+                r#"{{"e":"contractInfo","E":1,"s":"ETHUSDT","ps":"ETHUSDT","ct":"PERPETUAL","dt":4133404800000,"ot":1569398400000,"cs":"{status}"}}"#
+            ))
+            .unwrap()
+        };
+        let change = |previous: Option<&str>, current: Option<&str>| StatusChange {
+            symbol: "ETHUSDT".to_string(),
+            previous: previous.map(str::to_string),
+            current: current.map(str::to_string),
+        };
+        cache.apply_contract_info(&event("PENDING_TRADING"));
+        cache.apply_contract_info(&event("PENDING_TRADING"));
+        assert_eq!(
+            changes.next().await,
+            Some(change(None, Some("PENDING_TRADING")))
+        );
+
+        // A refresh before the exchange lists it keeps the announcement
+        cache.replace(vec![market()]);
+        let mut eth = market();
+        eth.symbol = "ETHUSDT".to_string();
+        eth.pair = "ETHUSDT".to_string();
+        eth.status = "PENDING_TRADING".to_string();
+        cache.replace(vec![market(), eth.clone()]);
+        eth.status = "TRADING".to_string();
+        cache.replace(vec![market(), eth]);
+        assert_eq!(
+            changes.next().await,
+            Some(change(Some("PENDING_TRADING"), Some("TRADING")))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_spawn_refresh_reports_errors() {
+        let config = ClientConfig::default().with_rest_base_url("http://127.0.0.1:1".to_string());
+        let cache = ExchangeInfoCache::new(BinanceClient::usdm().with_config(config));
+        let (errors, mut received) = tokio::sync::mpsc::unbounded_channel();
+        let handle = cache.spawn_refresh(Duration::from_secs(60), move |error| {
+            let _ = errors.send(error);
+        });
+        assert!(matches!(
+            received.recv().await,
+            Some(RequestError::Request(_))
+        ));
+        handle.abort();
+    }
+}
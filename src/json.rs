@@ -0,0 +1,89 @@
+use serde::Deserialize;
+use std::fmt::Display;
+
+// Parses a text frame of a stream or of the WS API, with simd-json when the
+// `simd_json` feature is enabled. simd-json works in place, which is why the
+// frame is taken mutably, and as bytes since it doesn't stay UTF-8. Frames
+// that don't parse are a bug of this crate.
+
+#[cfg(not(feature = "simd_json"))]
+pub(crate) fn parse_frame<'a, T: Deserialize<'a>>(frame: &'a mut [u8]) -> T {
+    match serde_json::from_slice(frame) {
+        Ok(r) => r,
+        Err(e) => parse_failed(frame, e),
+    }
+}
+
+#[cfg(feature = "simd_json")]
+thread_local! {
+    // Allocating the parser's buffers for each frame costs more than SIMD saves
+    static BUFFERS: std::cell::RefCell<simd_json::Buffers> = Default::default();
+    // The frame as received, simd-json overwrites it while parsing
+    static RECEIVED: std::cell::Cell<Vec<u8>> = Default::default();
+}
+
+#[cfg(feature = "simd_json")]
+pub(crate) fn parse_frame<'a, T: Deserialize<'a>>(frame: &'a mut [u8]) -> T {
+    // Taken out of the cell, a Deserialize impl parsing a frame itself gets
+    // its own copy
+    let mut received = RECEIVED.take();
+    received.clear();
+    received.extend_from_slice(frame);
+    let parsed = BUFFERS.with(|buffers| match buffers.try_borrow_mut() {
+        Ok(mut buffers) => simd_json::serde::from_slice_with_buffers(frame, &mut buffers),
+        // Only when a Deserialize impl parses a frame itself
+        Err(_) => simd_json::serde::from_slice(frame),
+    });
+    match parsed {
+        Ok(r) => {
+            RECEIVED.set(received);
+            r
+        }
+        Err(e) => parse_failed(&received, e),
+    }
+}
+
+pub(crate) fn parse_failed(frame: &[u8], e: impl Display) -> ! {
+    eprintln!("Failed to parse event:");
+    match serde_json::from_slice::<serde_json::Value>(frame) {
+        Ok(val) => eprintln!("{:#?}", val),
+        Err(_) => eprintln!("{}", String::from_utf8_lossy(frame)),
+    }
+    panic!("parsing error: {}", e);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        rest::filters::SymbolFilter, websocket::spot::AggTradeEventRef, ws_api::WsApiEvent,
+    };
+
+    #[test]
+    fn test_parse_frame() {
+        let mut frame = r#"{"id":1,"status":200,"result":[{"filterType":"PRICE_FILTER","minPrice":"0.1","maxPrice":"1000000","tickSize":"0.1"},{"filterType":"SOMETHING_NEW","foo":1}],"rateLimits":[{"rateLimitType":"REQUEST_WEIGHT","interval":"MINUTE","intervalNum":1,"limit":2400,"count":2}]}"#.as_bytes().to_vec();
+        let event: WsApiEvent<Vec<SymbolFilter>> = parse_frame(&mut frame);
+        let filters = event.result.unwrap();
+        assert!(matches!(filters[0], SymbolFilter::PriceFilter { .. }));
+        assert!(matches!(filters[1], SymbolFilter::Other(_)));
+        assert_eq!(event.rate_limits[0].count, 2);
+
+        let mut frame = r#"{"id":2,"status":400,"error":{"code":-1121,"msg":"Invalid symbol."},"rateLimits":[]}"#.as_bytes().to_vec();
+        let event: WsApiEvent<Vec<SymbolFilter>> = parse_frame(&mut frame);
+        assert_eq!(event.result.unwrap_err().code, -1121);
+
+        let mut frame = r#"{"e":"aggTrade","E":1,"s":"BTCUSDT","a":2,"p":"37012.4","q":"0.015","f":3,"l":4,"T":5,"m":true,"x":"é\n"}"#.as_bytes().to_vec();
+        let event: AggTradeEventRef = parse_frame(&mut frame);
+        assert_eq!(
+            (event.symbol, event.price, event.qty),
+            ("BTCUSDT", "37012.4", "0.015")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "parsing error")]
+    fn test_parse_frame_error() {
+        let mut frame = br#"{"id":1,"status":"OK","rateLimits":[]}"#.to_vec();
+        let _: WsApiEvent<Vec<SymbolFilter>> = parse_frame(&mut frame);
+    }
+}
@@ -1,8 +1,29 @@
 #![feature(string_from_utf8_lossy_owned)]
 
+// Serializes a struct that Binance sends as an array back into one, with the
+// fields in the given order
+macro_rules! serialize_as_tuple {
+    ($name:ident { $($field:ident),+ $(,)? }) => {
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::SerializeTuple;
+                let mut tuple = serializer.serialize_tuple([$(stringify!($field)),+].len())?;
+                $(tuple.serialize_element(&self.$field)?;)+
+                tuple.end()
+            }
+        }
+    };
+}
+
 pub mod client;
+pub mod decimal;
+pub mod enums;
 pub mod errors;
+pub mod exchange_info;
+mod json;
 pub mod response;
 pub mod rest;
+pub mod rounding;
+pub mod validation;
 pub mod websocket;
 pub mod ws_api;
@@ -6,3 +6,48 @@ pub struct Response<T> {
     pub headers: Box<HeaderMap>,
     pub content: T,
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::json::parse_frame;
+    use serde::{de::DeserializeOwned, Serialize};
+    #[cfg(not(feature = "readable_names"))]
+    use serde_json::Value;
+
+    // Fields the models leave out, like the event type of stream events, may
+    // be missing from the serialized form, and fields Binance left out may be
+    // serialized as null
+    #[cfg(not(feature = "readable_names"))]
+    fn assert_subset(serialized: &Value, fixture: &Value, path: &str) {
+        match (serialized, fixture) {
+            (Value::Object(serialized), Value::Object(fixture)) => {
+                for (key, value) in serialized {
+                    let path = format!("{}.{}", path, key);
+                    match fixture.get(key) {
+                        Some(expected) => assert_subset(value, expected, &path),
+                        None => assert_eq!(value, &Value::Null, "{} is not in the fixture", path),
+                    }
+                }
+            }
+            (Value::Array(serialized), Value::Array(fixture)) => {
+                assert_eq!(serialized.len(), fixture.len(), "{}", path);
+                for (i, (value, expected)) in serialized.iter().zip(fixture).enumerate() {
+                    assert_subset(value, expected, &format!("{}[{}]", path, i));
+                }
+            }
+            _ => assert_eq!(serialized, fixture, "{}", path),
+        }
+    }
+
+    // Parses a Binance payload like a received frame and checks that it
+    // serializes back under the same names and values, and parses again from
+    // that. Readable names can't match the fixture, they only parse back.
+    pub(crate) fn assert_round_trip<T: DeserializeOwned + Serialize>(fixture: &str) {
+        let model: T = parse_frame(&mut fixture.as_bytes().to_vec());
+        let serialized = serde_json::to_value(&model).unwrap();
+        #[cfg(not(feature = "readable_names"))]
+        assert_subset(&serialized, &serde_json::from_str(fixture).unwrap(), "");
+        let model: T = parse_frame(&mut serialized.to_string().into_bytes());
+        assert_eq!(serde_json::to_value(&model).unwrap(), serialized);
+    }
+}
@@ -0,0 +1,1610 @@
+use crate::{
+    client::Coinm,
+    decimal::{Decimal, DecimalParam},
+    enums::{
+        OrderStatus, OrderType, PositionSide, PriceMatch, SelfTradePreventionMode, Side,
+        TimeInForce, WorkingType,
+    },
+    errors::ContentError,
+};
+
+use super::{json_array, PublicRequest, SignedRequest};
+use binance_async_api_macros::wire_names;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+pub use super::usdm::{BookLevel, ExchangeFilter, RateLimit, SymbolFilter};
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct ExchangeInfoRequest;
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ExchangeInfoResponse {
+    pub exchange_filters: Vec<ExchangeFilter>,
+    pub rate_limits: Vec<RateLimit>,
+    pub server_time: u64,
+    pub symbols: Vec<Market>,
+    pub timezone: String,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Market {
+    pub symbol: String,
+    pub pair: String,
+    pub contract_type: String,
+    pub delivery_date: u64,
+    pub onboard_date: u64,
+    pub contract_status: String,
+    pub contract_size: u64, // value of one contract, in quote asset
+    pub margin_asset: String,
+    pub maint_margin_percent: String,
+    pub required_margin_percent: String,
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub price_precision: u64,
+    pub quantity_precision: u64,
+    pub base_asset_precision: u64,
+    pub quote_precision: u64,
+    pub equal_qty_precision: u64,
+    pub trigger_protect: String,
+    pub underlying_type: String,
+    pub underlying_sub_type: Vec<String>,
+    pub filters: Vec<SymbolFilter>,
+    pub order_types: Vec<OrderType>,
+    pub time_in_force: Vec<TimeInForce>,
+    pub liquidation_fee: Decimal,
+    pub market_take_bound: String,
+}
+
+impl PublicRequest<Coinm> for ExchangeInfoRequest {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/exchangeInfo";
+    type Response = ExchangeInfoResponse;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderBookRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrderBookResponse {
+    pub last_update_id: u64,
+    #[wire = "E"]
+    pub message_output_time: u64,
+    #[wire = "T"]
+    pub transaction_time: u64,
+    pub symbol: String,
+    pub pair: String,
+    pub bids: Vec<BookLevel>,
+    pub asks: Vec<BookLevel>,
+}
+
+impl PublicRequest<Coinm> for OrderBookRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/depth";
+    type Response = OrderBookResponse;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecentTradesRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 1000
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TradeResponse {
+    pub id: u64,
+    pub price: Decimal,
+    pub qty: Decimal,      // in contracts
+    pub base_qty: Decimal, // in base asset
+    pub time: u64,
+    pub is_buyer_maker: bool,
+}
+
+impl PublicRequest<Coinm> for RecentTradesRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/trades";
+    type Response = Vec<TradeResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentAggTradesRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AggTradeResponse {
+    #[wire = "a"]
+    pub id: u64,
+    #[wire = "p"]
+    pub price: Decimal,
+    #[wire = "q"]
+    pub qty: Decimal, // in contracts
+    #[wire = "f"]
+    pub first_trade_id: u64,
+    #[wire = "l"]
+    pub last_trade_id: u64,
+    #[wire = "T"]
+    pub timestamp: u64,
+    #[wire = "m"]
+    pub buyer_is_maker: bool,
+}
+
+impl PublicRequest<Coinm> for RecentAggTradesRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/aggTrades";
+    type Response = Vec<AggTradeResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PremiumIndexRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<Cow<'a, str>>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PremiumIndexResponse {
+    pub symbol: String,
+    pub pair: String,
+    pub mark_price: Decimal,
+    pub index_price: Decimal,
+    pub estimated_settle_price: Decimal,
+    pub last_funding_rate: String, // empty for delivery contracts
+    pub interest_rate: String,     // empty for delivery contracts
+    pub next_funding_time: u64,
+    pub time: u64,
+}
+
+impl PublicRequest<Coinm> for PremiumIndexRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/premiumIndex";
+    type Response = Vec<PremiumIndexResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRateHistoryRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 1000
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FundingRateResponse {
+    pub symbol: String,
+    pub funding_time: u64,
+    pub funding_rate: String,
+    pub mark_price: Option<Decimal>,
+}
+
+impl PublicRequest<Coinm> for FundingRateHistoryRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/fundingRate";
+    type Response = Vec<FundingRateResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KlinesRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    pub interval: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 1500
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContinuousKlinesRequest<'a> {
+    pub pair: Cow<'a, str>,
+    pub contract_type: Cow<'a, str>, // PERPETUAL, CURRENT_QUARTER or NEXT_QUARTER
+    pub interval: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 1500
+}
+
+// Klines are sent as arrays, in this field order
+#[derive(Debug, Clone, Deserialize)]
+pub struct KlineResponse {
+    pub open_time: u64,
+    pub open_price: Decimal,
+    pub high_price: Decimal,
+    pub low_price: Decimal,
+    pub close_price: Decimal,
+    pub volume: Decimal, // in contracts
+    pub close_time: u64,
+    pub base_volume: Decimal,
+    pub trade_count: u64,
+    pub taker_buy_volume: Decimal,
+    pub taker_buy_base_volume: Decimal,
+    pub unused: String,
+}
+
+serialize_as_tuple!(KlineResponse {
+    open_time,
+    open_price,
+    high_price,
+    low_price,
+    close_price,
+    volume,
+    close_time,
+    base_volume,
+    trade_count,
+    taker_buy_volume,
+    taker_buy_base_volume,
+    unused
+});
+
+impl PublicRequest<Coinm> for KlinesRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/klines";
+    type Response = Vec<KlineResponse>;
+}
+
+impl PublicRequest<Coinm> for ContinuousKlinesRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/continuousKlines";
+    type Response = Vec<KlineResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexPriceKlinesRequest<'a> {
+    pub pair: Cow<'a, str>,
+    pub interval: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 1500
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPriceKlinesRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    pub interval: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 1500
+}
+
+// Index and mark price klines only fill the price fields, the others are unused
+#[derive(Debug, Clone, Deserialize)]
+pub struct PriceKlineResponse {
+    pub open_time: u64,
+    pub open_price: Decimal,
+    pub high_price: Decimal,
+    pub low_price: Decimal,
+    pub close_price: Decimal,
+    pub unused_1: String,
+    pub close_time: u64,
+    pub unused_2: String,
+    pub component_count: u64,
+    pub unused_3: String,
+    pub unused_4: String,
+    pub unused_5: String,
+}
+
+serialize_as_tuple!(PriceKlineResponse {
+    open_time,
+    open_price,
+    high_price,
+    low_price,
+    close_price,
+    unused_1,
+    close_time,
+    unused_2,
+    component_count,
+    unused_3,
+    unused_4,
+    unused_5
+});
+
+impl PublicRequest<Coinm> for IndexPriceKlinesRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/indexPriceKlines";
+    type Response = Vec<PriceKlineResponse>;
+}
+
+impl PublicRequest<Coinm> for MarkPriceKlinesRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/markPriceKlines";
+    type Response = Vec<PriceKlineResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Ticker24hrRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<Cow<'a, str>>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Ticker24hrResponse {
+    pub symbol: String,
+    pub pair: String,
+    pub price_change: Decimal,
+    pub price_change_percent: String,
+    pub weighted_avg_price: Decimal,
+    pub last_price: Decimal,
+    pub last_qty: Decimal,
+    pub open_price: Decimal,
+    pub high_price: Decimal,
+    pub low_price: Decimal,
+    pub volume: Decimal,      // in contracts
+    pub base_volume: Decimal, // in base asset
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: i64,
+    pub last_id: i64,
+    pub count: u64,
+}
+
+impl PublicRequest<Coinm> for Ticker24hrRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/ticker/24hr";
+    type Response = Vec<Ticker24hrResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PriceTickerRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<Cow<'a, str>>,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PriceTickerResponse {
+    pub symbol: String,
+    #[wire = "ps"]
+    pub pair: String,
+    pub price: Decimal,
+    pub time: u64,
+}
+
+impl PublicRequest<Coinm> for PriceTickerRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/ticker/price";
+    type Response = Vec<PriceTickerResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BookTickerRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<Cow<'a, str>>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BookTickerResponse {
+    pub symbol: String,
+    pub pair: String,
+    pub bid_price: Decimal,
+    pub bid_qty: Decimal,
+    pub ask_price: Decimal,
+    pub ask_qty: Decimal,
+    pub time: u64,
+}
+
+impl PublicRequest<Coinm> for BookTickerRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/ticker/bookTicker";
+    type Response = Vec<BookTickerResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OpenInterestRequest<'a> {
+    pub symbol: Cow<'a, str>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OpenInterestResponse {
+    pub symbol: String,
+    pub pair: String,
+    pub open_interest: Decimal, // in contracts
+    pub contract_type: String,
+    pub time: u64,
+}
+
+impl PublicRequest<Coinm> for OpenInterestRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/openInterest";
+    type Response = OpenInterestResponse;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestHistoryRequest<'a> {
+    pub pair: Cow<'a, str>,
+    pub contract_type: Cow<'a, str>, // ALL, CURRENT_QUARTER, NEXT_QUARTER or PERPETUAL
+    pub period: Cow<'a, str>,        // 5m, 15m, 30m, 1h, 2h, 4h, 6h, 12h or 1d
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OpenInterestHistoryResponse {
+    pub pair: String,
+    pub contract_type: String,
+    pub sum_open_interest: Decimal,       // in contracts
+    pub sum_open_interest_value: Decimal, // in base asset
+    pub timestamp: u64,
+}
+
+impl PublicRequest<Coinm> for OpenInterestHistoryRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/futures/data/openInterestHist";
+    type Response = Vec<OpenInterestHistoryResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LongShortRatioRequest<'a> {
+    pub pair: Cow<'a, str>,
+    pub period: Cow<'a, str>, // 5m, 15m, 30m, 1h, 2h, 4h, 6h, 12h or 1d
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+}
+
+// Same parameters, on each of the three long/short ratio endpoints
+#[derive(Debug, Clone, Serialize)]
+pub struct TopTraderAccountRatioRequest<'a>(pub LongShortRatioRequest<'a>);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TopTraderPositionRatioRequest<'a>(pub LongShortRatioRequest<'a>);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GlobalAccountRatioRequest<'a>(pub LongShortRatioRequest<'a>);
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LongShortAccountRatioResponse {
+    pub pair: String,
+    pub long_short_ratio: String,
+    pub long_account: String,
+    pub short_account: String,
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LongShortPositionRatioResponse {
+    pub pair: String,
+    pub long_short_ratio: String,
+    pub long_position: String,
+    pub short_position: String,
+    pub timestamp: u64,
+}
+
+impl PublicRequest<Coinm> for TopTraderAccountRatioRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/futures/data/topLongShortAccountRatio";
+    type Response = Vec<LongShortAccountRatioResponse>;
+}
+
+impl PublicRequest<Coinm> for TopTraderPositionRatioRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/futures/data/topLongShortPositionRatio";
+    type Response = Vec<LongShortPositionRatioResponse>;
+}
+
+impl PublicRequest<Coinm> for GlobalAccountRatioRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/futures/data/globalLongShortAccountRatio";
+    type Response = Vec<LongShortAccountRatioResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TakerBuySellVolumeRequest<'a> {
+    pub pair: Cow<'a, str>,
+    pub contract_type: Cow<'a, str>, // ALL, CURRENT_QUARTER, NEXT_QUARTER or PERPETUAL
+    pub period: Cow<'a, str>,        // 5m, 15m, 30m, 1h, 2h, 4h, 6h, 12h or 1d
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TakerBuySellVolumeResponse {
+    pub pair: String,
+    pub contract_type: String,
+    pub taker_buy_vol: Decimal,        // in contracts
+    pub taker_sell_vol: Decimal,       // in contracts
+    pub taker_buy_vol_value: Decimal,  // in base asset
+    pub taker_sell_vol_value: Decimal, // in base asset
+    pub timestamp: u64,
+}
+
+impl PublicRequest<Coinm> for TakerBuySellVolumeRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/futures/data/takerBuySellVol";
+    type Response = Vec<TakerBuySellVolumeResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BasisRequest<'a> {
+    pub pair: Cow<'a, str>,
+    pub contract_type: Cow<'a, str>, // CURRENT_QUARTER, NEXT_QUARTER or PERPETUAL
+    pub period: Cow<'a, str>,        // 5m, 15m, 30m, 1h, 2h, 4h, 6h, 12h or 1d
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BasisResponse {
+    pub pair: String,
+    pub contract_type: String,
+    pub index_price: Decimal,
+    pub futures_price: Decimal,
+    pub basis: String,
+    pub basis_rate: String,
+    pub annualized_basis_rate: String,
+    pub timestamp: u64,
+}
+
+impl PublicRequest<Coinm> for BasisRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/futures/data/basis";
+    type Response = Vec<BasisResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    pub side: Side,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_side: Option<PositionSide>,
+    pub r#type: OrderType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<DecimalParam<'a>>, // in contracts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_position: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activation_price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_rate: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_type: Option<WorkingType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_protect: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_match: Option<PriceMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+// Returned by order placement, modification and cancellation
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrderResponse {
+    pub client_order_id: String,
+    pub cum_qty: Decimal,
+    pub cum_base: Decimal,
+    pub executed_qty: Decimal,
+    pub order_id: u64,
+    pub avg_price: Decimal,
+    pub orig_qty: Decimal,
+    pub price: Decimal,
+    pub reduce_only: bool,
+    pub side: Side,
+    pub position_side: PositionSide,
+    pub status: OrderStatus,
+    pub stop_price: Decimal,
+    pub close_position: bool,
+    pub symbol: String,
+    pub pair: String,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub orig_type: OrderType,
+    pub activate_price: Option<Decimal>,
+    pub price_rate: Option<String>,
+    pub update_time: u64,
+    pub working_type: WorkingType,
+    pub price_protect: bool,
+    pub price_match: Option<PriceMatch>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+impl SignedRequest<Coinm> for NewOrderRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/dapi/v1/order";
+    const ORDER_ENTRY: bool = true;
+    type Response = OrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifyOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<Cow<'a, str>>,
+    pub side: Side,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<DecimalParam<'a>>, // in contracts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_match: Option<PriceMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<Coinm> for ModifyOrderRequest<'_> {
+    const METHOD: Method = Method::PUT;
+    const ENDPOINT: &'static str = "/dapi/v1/order";
+    const ORDER_ENTRY: bool = true;
+    type Response = OrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<Coinm> for CancelOrderRequest<'_> {
+    const METHOD: Method = Method::DELETE;
+    const ENDPOINT: &'static str = "/dapi/v1/order";
+    const ORDER_ENTRY: bool = true;
+    type Response = OrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+// Each order of a batch succeeds or fails on its own
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum BatchOrderResponse {
+    Order(OrderResponse),
+    Error(ContentError),
+}
+
+// Same parameters as `NewOrderRequest`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchOrder<'a> {
+    pub symbol: Cow<'a, str>,
+    pub side: Side,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_side: Option<PositionSide>,
+    pub r#type: OrderType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<DecimalParam<'a>>, // in contracts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activation_price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_rate: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_type: Option<WorkingType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_protect: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_match: Option<PriceMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewBatchOrdersRequest<'a> {
+    #[serde(serialize_with = "json_array")]
+    pub batch_orders: Vec<BatchOrder<'a>>, // at most 5
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<Coinm> for NewBatchOrdersRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/dapi/v1/batchOrders";
+    const ORDER_ENTRY: bool = true;
+    type Response = Vec<BatchOrderResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+// Same parameters as `ModifyOrderRequest`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchModification<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<Cow<'a, str>>,
+    pub side: Side,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<DecimalParam<'a>>, // in contracts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_match: Option<PriceMatch>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifyBatchOrdersRequest<'a> {
+    #[serde(serialize_with = "json_array")]
+    pub batch_orders: Vec<BatchModification<'a>>, // at most 5
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<Coinm> for ModifyBatchOrdersRequest<'_> {
+    const METHOD: Method = Method::PUT;
+    const ENDPOINT: &'static str = "/dapi/v1/batchOrders";
+    const ORDER_ENTRY: bool = true;
+    type Response = Vec<BatchOrderResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelBatchOrdersRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "json_array")]
+    pub order_id_list: Option<Vec<u64>>, // at most 10
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "json_array")]
+    pub orig_client_order_id_list: Option<Vec<Cow<'a, str>>>, // at most 10
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<Coinm> for CancelBatchOrdersRequest<'_> {
+    const METHOD: Method = Method::DELETE;
+    const ENDPOINT: &'static str = "/dapi/v1/batchOrders";
+    const ORDER_ENTRY: bool = true;
+    type Response = Vec<BatchOrderResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllOpenOrdersRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CodeResponse {
+    pub code: i64,
+    pub msg: String,
+}
+
+impl SignedRequest<Coinm> for CancelAllOpenOrdersRequest<'_> {
+    const METHOD: Method = Method::DELETE;
+    const ENDPOINT: &'static str = "/dapi/v1/allOpenOrders";
+    const ORDER_ENTRY: bool = true;
+    type Response = CodeResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownCancelAllRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    pub countdown_time: u64, // in ms, 0 stops the countdown
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CountdownCancelAllResponse {
+    pub symbol: String,
+    pub countdown_time: String,
+}
+
+impl SignedRequest<Coinm> for CountdownCancelAllRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/dapi/v1/countdownCancelAll";
+    const ORDER_ENTRY: bool = true;
+    type Response = CountdownCancelAllResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct QueryOrderResponse {
+    pub avg_price: Decimal,
+    pub client_order_id: String,
+    pub cum_base: Decimal,
+    pub executed_qty: Decimal,
+    pub order_id: u64,
+    pub orig_qty: Decimal,
+    pub orig_type: OrderType,
+    pub price: Decimal,
+    pub reduce_only: bool,
+    pub side: Side,
+    pub position_side: PositionSide,
+    pub status: OrderStatus,
+    pub stop_price: Decimal,
+    pub close_position: bool,
+    pub symbol: String,
+    pub pair: String,
+    pub time: u64,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub activate_price: Option<Decimal>,
+    pub price_rate: Option<String>,
+    pub update_time: u64,
+    pub working_type: WorkingType,
+    pub price_protect: bool,
+    pub price_match: Option<PriceMatch>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+impl SignedRequest<Coinm> for QueryOrderRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/order";
+    type Response = QueryOrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryOpenOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<Coinm> for QueryOpenOrderRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/openOrder";
+    type Response = QueryOrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOrdersRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<Coinm> for OpenOrdersRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/openOrders";
+    type Response = Vec<QueryOrderResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AllOrdersRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<Coinm> for AllOrdersRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/allOrders";
+    type Response = Vec<QueryOrderResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForceOrdersRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_close_type: Option<Cow<'a, str>>, // LIQUIDATION or ADL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<Coinm> for ForceOrdersRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/forceOrders";
+    type Response = Vec<QueryOrderResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformationRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AccountInformationResponse {
+    pub assets: Vec<AccountAsset>,
+    pub positions: Vec<AccountPosition>,
+    pub can_deposit: bool,
+    pub can_trade: bool,
+    pub can_withdraw: bool,
+    pub fee_tier: u64,
+    pub update_time: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AccountAsset {
+    pub asset: String,
+    pub wallet_balance: Decimal,
+    pub unrealized_profit: Decimal,
+    pub margin_balance: Decimal,
+    pub maint_margin: Decimal,
+    pub initial_margin: Decimal,
+    pub position_initial_margin: Decimal,
+    pub open_order_initial_margin: Decimal,
+    pub max_withdraw_amount: Decimal,
+    pub cross_wallet_balance: Decimal,
+    pub cross_un_pnl: Decimal,
+    pub available_balance: Decimal,
+    pub update_time: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AccountPosition {
+    pub symbol: String,
+    pub position_amt: Decimal, // in contracts
+    pub initial_margin: Decimal,
+    pub maint_margin: Decimal,
+    pub unrealized_profit: Decimal,
+    pub position_initial_margin: Decimal,
+    pub open_order_initial_margin: Decimal,
+    pub leverage: String,
+    pub isolated: bool,
+    pub position_side: PositionSide,
+    pub entry_price: Decimal,
+    pub break_even_price: Option<Decimal>,
+    pub max_qty: Decimal,
+    pub notional_value: Option<Decimal>,
+    pub isolated_wallet: Option<Decimal>,
+    pub update_time: u64,
+}
+
+impl SignedRequest<Coinm> for AccountInformationRequest {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/account";
+    type Response = AccountInformationResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BalanceResponse {
+    pub account_alias: String,
+    pub asset: String,
+    pub balance: Decimal,
+    pub withdraw_available: Decimal,
+    pub cross_wallet_balance: Decimal,
+    pub cross_un_pnl: Decimal,
+    pub available_balance: Decimal,
+    pub update_time: u64,
+}
+
+impl SignedRequest<Coinm> for BalanceRequest {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/balance";
+    type Response = Vec<BalanceResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRiskRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin_asset: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PositionRiskResponse {
+    pub symbol: String,
+    pub position_amt: Decimal, // in contracts
+    pub entry_price: Decimal,
+    pub break_even_price: Option<Decimal>,
+    pub mark_price: Decimal,
+    pub un_realized_profit: Decimal,
+    pub liquidation_price: Decimal,
+    pub leverage: String,
+    pub max_qty: Decimal,
+    pub margin_type: String,
+    pub isolated_margin: Decimal,
+    pub is_auto_add_margin: String,
+    pub position_side: PositionSide,
+    pub notional_value: Decimal,
+    pub isolated_wallet: Decimal,
+    pub update_time: u64,
+}
+
+impl SignedRequest<Coinm> for PositionRiskRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/positionRisk";
+    type Response = Vec<PositionRiskResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeLeverageRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    pub leverage: u64, // 1 to 125
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ChangeLeverageResponse {
+    pub leverage: u64,
+    pub max_qty: Decimal, // in contracts
+    pub symbol: String,
+}
+
+impl SignedRequest<Coinm> for ChangeLeverageRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/dapi/v1/leverage";
+    type Response = ChangeLeverageResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeMarginTypeRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    pub margin_type: Cow<'a, str>, // ISOLATED or CROSSED
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<Coinm> for ChangeMarginTypeRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/dapi/v1/marginType";
+    type Response = CodeResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifyPositionMarginRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_side: Option<PositionSide>,
+    pub amount: DecimalParam<'a>,
+    pub r#type: u8, // 1 to add margin, 2 to reduce it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ModifyPositionMarginResponse {
+    pub amount: f64,
+    pub code: i64,
+    pub msg: String,
+    pub r#type: u8,
+}
+
+impl SignedRequest<Coinm> for ModifyPositionMarginRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/dapi/v1/positionMargin";
+    type Response = ModifyPositionMarginResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountTradesRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 1000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AccountTradeResponse {
+    pub symbol: String,
+    pub id: u64,
+    pub order_id: u64,
+    pub pair: String,
+    pub side: Side,
+    pub price: Decimal,
+    pub qty: Decimal, // in contracts
+    pub realized_pnl: Decimal,
+    pub margin_asset: String,
+    pub base_qty: Decimal,
+    pub commission: Decimal,
+    pub commission_asset: String,
+    pub time: u64,
+    pub position_side: PositionSide,
+    pub buyer: bool,
+    pub maker: bool,
+}
+
+impl SignedRequest<Coinm> for AccountTradesRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/userTrades";
+    type Response = Vec<AccountTradeResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomeHistoryRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub income_type: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 1000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IncomeResponse {
+    pub symbol: String,
+    pub income_type: String,
+    pub income: Decimal,
+    pub asset: String,
+    pub info: String,
+    pub time: u64,
+    pub tran_id: u64,
+    pub trade_id: String,
+}
+
+impl SignedRequest<Coinm> for IncomeHistoryRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/income";
+    type Response = Vec<IncomeResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeverageBracketRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LeverageBracketResponse {
+    pub pair: String,
+    pub brackets: Vec<LeverageBracket>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LeverageBracket {
+    pub bracket: u64,
+    pub initial_leverage: u64,
+    pub qty_cap: f64, // in base asset
+    #[serde(alias = "qtylFloor")]
+    pub qty_floor: f64, // in base asset
+    pub maint_margin_ratio: f64,
+    pub cum: f64,
+}
+
+impl SignedRequest<Coinm> for LeverageBracketRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/leverageBracket";
+    type Response = Vec<LeverageBracketResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdlQuantileRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AdlQuantileResponse {
+    pub symbol: String,
+    pub adl_quantile: AdlQuantile,
+}
+
+// One-way mode fills `both`, hedge mode fills `long`, `short` and `hedge`
+#[wire_names(UPPERCASE)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AdlQuantile {
+    pub long: Option<u64>,
+    pub short: Option<u64>,
+    pub hedge: Option<u64>,
+    pub both: Option<u64>,
+}
+
+impl SignedRequest<Coinm> for AdlQuantileRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/dapi/v1/adlQuantile";
+    type Response = Vec<AdlQuantileResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::BinanceClient;
+
+    #[tokio::test]
+    async fn test_exchange_info_request() {
+        let client = BinanceClient::coinm();
+        let req = ExchangeInfoRequest;
+        let res = client.request(&req).await.unwrap();
+        assert!(res.status.is_success());
+    }
+
+    #[tokio::test]
+    async fn test_order_book_request() {
+        let client = BinanceClient::coinm();
+        let req = OrderBookRequest {
+            symbol: "BTCUSD_PERP".into(),
+            limit: Some(5),
+        };
+        let res = client.request(&req).await.unwrap();
+        assert!(res.status.is_success());
+    }
+
+    #[tokio::test]
+    async fn test_premium_index_request() {
+        let client = BinanceClient::coinm();
+        let req = PremiumIndexRequest {
+            symbol: None,
+            pair: Some("BTCUSD".into()),
+        };
+        let res = client.request(&req).await.unwrap();
+        assert!(res.status.is_success());
+    }
+
+    #[test]
+    fn test_response_round_trip() {
+        use crate::response::tests::assert_round_trip;
+        assert_round_trip::<OrderBookResponse>(
+            r#"{"lastUpdateId":16769853,"symbol":"BTCUSD_PERP","pair":"BTCUSD","E":1591250106370,"T":1591250106368,"bids":[["9638","431"]],"asks":[["9638.2","12"]]}"#,
+        );
+        assert_round_trip::<Vec<PremiumIndexResponse>>(
+            r#"[{"symbol":"BTCUSD_PERP","pair":"BTCUSD","markPrice":"11029.69574559","indexPrice":"10979.144375","estimatedSettlePrice":"10981.74168236","lastFundingRate":"0.00071003","interestRate":"0.00010000","nextFundingTime":1596096000000,"time":1596094042000}]"#,
+        );
+        assert_round_trip::<Vec<BatchOrderResponse>>(
+            r#"[{"clientOrderId":"testOrder","cumQty":"0","cumBase":"0","executedQty":"0","orderId":22542179,"avgPrice":"0","origQty":"10","price":"0","reduceOnly":false,"side":"BUY","positionSide":"SHORT","status":"NEW","stopPrice":"9300","closePosition":false,"symbol":"BTCUSD_200925","pair":"BTCUSD","timeInForce":"GTC","type":"TRAILING_STOP_MARKET","origType":"TRAILING_STOP_MARKET","activatePrice":"9020","priceRate":"0.3","updateTime":1566818724722,"workingType":"CONTRACT_PRICE","priceProtect":false,"priceMatch":"NONE","selfTradePreventionMode":"NONE"},{"code":-2022,"msg":"ReduceOnly Order is rejected."}]"#,
+        );
+        assert_round_trip::<Vec<PositionRiskResponse>>(
+            r#"[{"symbol":"BTCUSD_201225","positionAmt":"0","entryPrice":"0","breakEvenPrice":"0","markPrice":"0","unRealizedProfit":"0","liquidationPrice":"0","leverage":"125","maxQty":"50","marginType":"cross","isolatedMargin":"0","isAutoAddMargin":"false","positionSide":"BOTH","notionalValue":"0","isolatedWallet":"0","updateTime":0}]"#,
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use crate::{
+    decimal::Decimal,
+    enums::{RateLimitInterval, RateLimitType},
+};
+use binance_async_api_macros::wire_names;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+// Spot and futures send some filters under the same type with different
+// field names, the futures names are used and the spot ones are aliases.
+// Filters of a type this crate doesn't know end up in `Other`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(
+    remote = "Self",
+    tag = "filterType",
+    rename_all = "SCREAMING_SNAKE_CASE"
+)]
+pub enum SymbolFilter {
+    #[serde(rename_all = "camelCase")]
+    PriceFilter {
+        min_price: Decimal,
+        max_price: Decimal,
+        tick_size: Decimal,
+    },
+    #[serde(rename_all = "camelCase")]
+    PercentPrice {
+        multiplier_up: Decimal,
+        multiplier_down: Decimal,
+        multiplier_decimal: Option<String>, // futures only
+        avg_price_mins: Option<u64>,        // spot only
+    },
+    #[serde(rename_all = "camelCase")]
+    PercentPriceBySide {
+        bid_multiplier_up: Decimal,
+        bid_multiplier_down: Decimal,
+        ask_multiplier_up: Decimal,
+        ask_multiplier_down: Decimal,
+        avg_price_mins: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    LotSize {
+        min_qty: Decimal,
+        max_qty: Decimal,
+        step_size: Decimal,
+    },
+    #[serde(rename_all = "camelCase")]
+    MarketLotSize {
+        min_qty: Decimal,
+        max_qty: Decimal,
+        step_size: Decimal,
+    },
+    #[serde(rename_all = "camelCase")]
+    MinNotional {
+        #[serde(alias = "minNotional")]
+        notional: Decimal,
+        apply_to_market: Option<bool>, // spot only
+        avg_price_mins: Option<u64>,   // spot only
+    },
+    #[serde(rename_all = "camelCase")]
+    Notional {
+        min_notional: Decimal,
+        apply_min_to_market: bool,
+        max_notional: Decimal,
+        apply_max_to_market: bool,
+        avg_price_mins: u64,
+    },
+    IcebergParts {
+        limit: u64,
+    },
+    MaxNumOrders {
+        #[serde(alias = "maxNumOrders")]
+        limit: u64,
+    },
+    MaxNumAlgoOrders {
+        #[serde(alias = "maxNumAlgoOrders")]
+        limit: u64,
+    },
+    MaxNumIcebergOrders {
+        #[serde(rename = "maxNumIcebergOrders")]
+        limit: u64,
+    },
+    MaxNumOrderLists {
+        #[serde(rename = "maxNumOrderLists")]
+        limit: u64,
+    },
+    MaxNumOrderAmends {
+        #[serde(rename = "maxNumOrderAmends")]
+        limit: u64,
+    },
+    MaxPosition {
+        #[serde(rename = "maxPosition")]
+        max_position: Decimal,
+    },
+    #[serde(rename_all = "camelCase")]
+    TrailingDelta {
+        min_trailing_above_delta: u64,
+        max_trailing_above_delta: u64,
+        min_trailing_below_delta: u64,
+        max_trailing_below_delta: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    TPlusSell {
+        end_time: Option<u64>,
+    },
+    #[serde(skip)]
+    Other(Value),
+}
+
+// The `filterType` of each variant but `Other`
+const SYMBOL_FILTER_TYPES: &[&str] = &[
+    "PRICE_FILTER",
+    "PERCENT_PRICE",
+    "PERCENT_PRICE_BY_SIDE",
+    "LOT_SIZE",
+    "MARKET_LOT_SIZE",
+    "MIN_NOTIONAL",
+    "NOTIONAL",
+    "ICEBERG_PARTS",
+    "MAX_NUM_ORDERS",
+    "MAX_NUM_ALGO_ORDERS",
+    "MAX_NUM_ICEBERG_ORDERS",
+    "MAX_NUM_ORDER_LISTS",
+    "MAX_NUM_ORDER_AMENDS",
+    "MAX_POSITION",
+    "TRAILING_DELTA",
+    "T_PLUS_SELL",
+];
+
+impl<'de> Deserialize<'de> for SymbolFilter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        known_or_other(
+            value,
+            SYMBOL_FILTER_TYPES,
+            |value| SymbolFilter::deserialize(value),
+            SymbolFilter::Other,
+        )
+    }
+}
+
+impl Serialize for SymbolFilter {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            SymbolFilter::Other(value) => value.serialize(serializer),
+            known => SymbolFilter::serialize(known, serializer),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(
+    remote = "Self",
+    tag = "filterType",
+    rename_all = "SCREAMING_SNAKE_CASE"
+)]
+pub enum ExchangeFilter {
+    ExchangeMaxNumOrders {
+        #[serde(rename = "maxNumOrders")]
+        limit: u64,
+    },
+    ExchangeMaxNumAlgoOrders {
+        #[serde(rename = "maxNumAlgoOrders")]
+        limit: u64,
+    },
+    ExchangeMaxNumIcebergOrders {
+        #[serde(rename = "maxNumIcebergOrders")]
+        limit: u64,
+    },
+    ExchangeMaxNumOrderLists {
+        #[serde(rename = "maxNumOrderLists")]
+        limit: u64,
+    },
+    #[serde(skip)]
+    Other(Value),
+}
+
+// The `filterType` of each variant but `Other`
+const EXCHANGE_FILTER_TYPES: &[&str] = &[
+    "EXCHANGE_MAX_NUM_ORDERS",
+    "EXCHANGE_MAX_NUM_ALGO_ORDERS",
+    "EXCHANGE_MAX_NUM_ICEBERG_ORDERS",
+    "EXCHANGE_MAX_NUM_ORDER_LISTS",
+];
+
+impl<'de> Deserialize<'de> for ExchangeFilter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        known_or_other(
+            value,
+            EXCHANGE_FILTER_TYPES,
+            |value| ExchangeFilter::deserialize(value),
+            ExchangeFilter::Other,
+        )
+    }
+}
+
+impl Serialize for ExchangeFilter {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ExchangeFilter::Other(value) => value.serialize(serializer),
+            known => ExchangeFilter::serialize(known, serializer),
+        }
+    }
+}
+
+// Only an unknown `filterType` falls back to `Other`, a known filter that
+// doesn't parse is an error rather than silently losing its fields.
+fn known_or_other<T, E: de::Error>(
+    value: Value,
+    known: &[&str],
+    parse: fn(&Value) -> Result<T, serde_json::Error>,
+    other: fn(Value) -> T,
+) -> Result<T, E> {
+    match value.get("filterType").and_then(Value::as_str) {
+        Some(filter_type) if known.contains(&filter_type) => parse(&value).map_err(E::custom),
+        Some(_) => Ok(other(value)),
+        None => Err(E::missing_field("filterType")),
+    }
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RateLimit {
+    pub rate_limit_type: RateLimitType,
+    pub interval: RateLimitInterval,
+    pub interval_num: u64,
+    pub limit: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filters_deserialization() {
+        let filters: Vec<SymbolFilter> = serde_json::from_str(
+            r#"[
+                {"filterType":"PRICE_FILTER","minPrice":"0.01","maxPrice":"1000000.00","tickSize":"0.01"},
+                {"filterType":"MIN_NOTIONAL","notional":"5"},
+                {"filterType":"MIN_NOTIONAL","minNotional":"10.0","applyToMarket":true,"avgPriceMins":5},
+                {"filterType":"MAX_NUM_ORDERS","limit":200},
+                {"filterType":"MAX_NUM_ORDERS","maxNumOrders":200},
+                {"filterType":"PERCENT_PRICE_BY_SIDE","bidMultiplierUp":"5","bidMultiplierDown":"0.2","askMultiplierUp":"5","askMultiplierDown":"0.2","avgPriceMins":5},
+                {"filterType":"SOMETHING_NEW","foo":1}
+            ]"#,
+        )
+        .unwrap();
+        assert!(matches!(filters[0], SymbolFilter::PriceFilter { .. }));
+        assert!(matches!(filters[1], SymbolFilter::MinNotional { .. }));
+        assert!(matches!(filters[2], SymbolFilter::MinNotional { .. }));
+        assert!(matches!(
+            filters[3],
+            SymbolFilter::MaxNumOrders { limit: 200 }
+        ));
+        assert!(matches!(
+            filters[4],
+            SymbolFilter::MaxNumOrders { limit: 200 }
+        ));
+        assert!(matches!(
+            filters[5],
+            SymbolFilter::PercentPriceBySide { .. }
+        ));
+        assert!(matches!(filters[6], SymbolFilter::Other(_)));
+
+        let filters: Vec<ExchangeFilter> = serde_json::from_str(
+            r#"[{"filterType":"EXCHANGE_MAX_NUM_ORDERS","maxNumOrders":1000},{"filterType":"NEW"}]"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            filters[0],
+            ExchangeFilter::ExchangeMaxNumOrders { limit: 1000 }
+        ));
+        assert!(matches!(filters[1], ExchangeFilter::Other(_)));
+        assert_eq!(
+            serde_json::to_string(&filters).unwrap(),
+            r#"[{"filterType":"EXCHANGE_MAX_NUM_ORDERS","maxNumOrders":1000},{"filterType":"NEW"}]"#
+        );
+    }
+
+    #[test]
+    fn test_malformed_known_filter() {
+        let err = serde_json::from_str::<SymbolFilter>(
+            r#"{"filterType":"LOT_SIZE","minQty":"0.1","maxQty":"10"}"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("stepSize"), "{err}");
+        assert!(serde_json::from_str::<ExchangeFilter>(
+            r#"{"filterType":"EXCHANGE_MAX_NUM_ORDERS","maxNumOrders":"many"}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<SymbolFilter>(r#"{"minQty":"0.1"}"#).is_err());
+    }
+
+    #[test]
+    fn test_known_filter_types() {
+        // Listed types parse as their variant, so they only miss fields
+        for filter_type in SYMBOL_FILTER_TYPES {
+            let filter = format!(r#"{{"filterType":"{filter_type}"}}"#);
+            match serde_json::from_str::<SymbolFilter>(&filter) {
+                Ok(filter) => assert!(!matches!(filter, SymbolFilter::Other(_))),
+                Err(err) => assert!(err.to_string().contains("missing field"), "{err}"),
+            }
+        }
+        for filter_type in EXCHANGE_FILTER_TYPES {
+            let filter = format!(r#"{{"filterType":"{filter_type}"}}"#);
+            let err = serde_json::from_str::<ExchangeFilter>(&filter).unwrap_err();
+            assert!(err.to_string().contains("missing field"), "{err}");
+        }
+    }
+}
@@ -0,0 +1,656 @@
+use crate::{
+    client::Margin,
+    decimal::{Decimal, DecimalParam},
+    enums::{OrderStatus, OrderType, SelfTradePreventionMode, Side, TimeInForce},
+};
+
+use super::{KeyedRequest, SignedRequest};
+use binance_async_api_macros::wire_names;
+use reqwest::Method;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::borrow::Cow;
+
+pub use super::spot::Fill;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_isolated: Option<Cow<'a, str>>, // TRUE or FALSE, defaults to FALSE
+    pub side: Side,
+    pub r#type: OrderType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_order_qty: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iceberg_qty: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<Cow<'a, str>>, // ACK, RESULT or FULL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side_effect_type: Option<Cow<'a, str>>, // NO_SIDE_EFFECT, MARGIN_BUY, AUTO_REPAY or AUTO_BORROW_REPAY
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_repay_at_cancel: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NewOrderAckResponse {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub is_isolated: bool,
+    pub transact_time: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NewOrderResultResponse {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub transact_time: u64,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub side: Side,
+    pub is_isolated: bool,
+    #[serde(default, deserialize_with = "string_or_number")]
+    pub margin_buy_borrow_amount: Option<String>,
+    pub margin_buy_borrow_asset: Option<String>,
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NewOrderFullResponse {
+    #[serde(flatten)]
+    pub result: NewOrderResultResponse,
+    pub fills: Vec<Fill>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum NewOrderResponse {
+    Full(NewOrderFullResponse),
+    Result(NewOrderResultResponse),
+    Ack(NewOrderAckResponse),
+}
+
+impl SignedRequest<Margin> for NewOrderRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/sapi/v1/margin/order";
+    const ORDER_ENTRY: bool = true;
+    type Response = NewOrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_isolated: Option<Cow<'a, str>>, // TRUE or FALSE, defaults to FALSE
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CancelOrderResponse {
+    pub symbol: String,
+    pub is_isolated: bool,
+    pub order_id: u64,
+    pub orig_client_order_id: String,
+    pub client_order_id: String,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub side: Side,
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+}
+
+impl SignedRequest<Margin> for CancelOrderRequest<'_> {
+    const METHOD: Method = Method::DELETE;
+    const ENDPOINT: &'static str = "/sapi/v1/margin/order";
+    const ORDER_ENTRY: bool = true;
+    type Response = CancelOrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_isolated: Option<Cow<'a, str>>, // TRUE or FALSE, defaults to FALSE
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct QueryOrderResponse {
+    pub symbol: String,
+    pub is_isolated: bool,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub side: Side,
+    pub stop_price: Decimal,
+    pub iceberg_qty: Decimal,
+    pub time: u64,
+    pub update_time: u64,
+    pub is_working: bool,
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+}
+
+impl SignedRequest<Margin> for QueryOrderRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/sapi/v1/margin/order";
+    type Response = QueryOrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowRepayRequest<'a> {
+    pub asset: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_isolated: Option<Cow<'a, str>>, // TRUE or FALSE, defaults to FALSE
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>, // isolated margin only
+    pub amount: DecimalParam<'a>,
+    pub r#type: Cow<'a, str>, // BORROW or REPAY
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BorrowRepayResponse {
+    pub tran_id: u64,
+}
+
+impl SignedRequest<Margin> for BorrowRepayRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/sapi/v1/margin/borrow-repay";
+    type Response = BorrowRepayResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxBorrowableRequest<'a> {
+    pub asset: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub isolated_symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MaxBorrowableResponse {
+    pub amount: Decimal,
+    pub borrow_limit: Decimal,
+}
+
+impl SignedRequest<Margin> for MaxBorrowableRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/sapi/v1/margin/maxBorrowable";
+    type Response = MaxBorrowableResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxTransferableRequest<'a> {
+    pub asset: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub isolated_symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MaxTransferableResponse {
+    pub amount: Decimal,
+}
+
+impl SignedRequest<Margin> for MaxTransferableRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/sapi/v1/margin/maxTransferable";
+    type Response = MaxTransferableResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossMarginAccountRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CrossMarginAccountResponse {
+    pub created: bool,
+    pub borrow_enabled: bool,
+    pub margin_level: String,
+    pub collateral_margin_level: String,
+    pub total_asset_of_btc: Decimal,
+    pub total_liability_of_btc: Decimal,
+    pub total_net_asset_of_btc: Decimal,
+    #[wire = "TotalCollateralValueInUSDT"]
+    pub total_collateral_value_in_usdt: Decimal,
+    pub total_open_order_loss_in_usdt: Decimal,
+    pub trade_enabled: bool,
+    pub transfer_in_enabled: bool,
+    pub transfer_out_enabled: bool,
+    pub account_type: String, // MARGIN_1 or MARGIN_2
+    pub user_assets: Vec<MarginAsset>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MarginAsset {
+    pub asset: String,
+    pub borrowed: Decimal,
+    pub free: Decimal,
+    pub interest: Decimal,
+    pub locked: Decimal,
+    pub net_asset: Decimal,
+}
+
+impl SignedRequest<Margin> for CrossMarginAccountRequest {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/sapi/v1/margin/account";
+    type Response = CrossMarginAccountResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAccountRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbols: Option<Cow<'a, str>>, // comma separated, at most 5 symbols
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IsolatedMarginAccountResponse {
+    pub assets: Vec<IsolatedMarginPair>,
+    pub total_asset_of_btc: Option<Decimal>,
+    pub total_liability_of_btc: Option<Decimal>,
+    pub total_net_asset_of_btc: Option<Decimal>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IsolatedMarginPair {
+    pub symbol: String,
+    pub base_asset: IsolatedMarginAsset,
+    pub quote_asset: IsolatedMarginAsset,
+    pub isolated_created: bool,
+    pub enabled: bool,
+    pub margin_level: String,
+    pub margin_level_status: String,
+    pub margin_ratio: String,
+    pub index_price: Decimal,
+    pub liquidate_price: Decimal,
+    pub liquidate_rate: String,
+    pub trade_enabled: bool,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IsolatedMarginAsset {
+    pub asset: String,
+    pub borrow_enabled: bool,
+    pub borrowed: Decimal,
+    pub free: Decimal,
+    pub interest: Decimal,
+    pub locked: Decimal,
+    pub net_asset: Decimal,
+    pub net_asset_of_btc: Decimal,
+    pub repay_enabled: bool,
+    pub total_asset: Decimal,
+}
+
+impl SignedRequest<Margin> for IsolatedMarginAccountRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/sapi/v1/margin/isolated/account";
+    type Response = IsolatedMarginAccountResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestHistoryRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub isolated_symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current: Option<u64>, // page, starts at 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>, // <= 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct InterestHistoryResponse {
+    pub rows: Vec<InterestRecord>,
+    pub total: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct InterestRecord {
+    pub tx_id: u64,
+    pub interest_accured_time: u64,
+    pub asset: String,
+    pub raw_asset: Option<String>,
+    pub principal: Decimal,
+    pub interest: Decimal,
+    pub interest_rate: String,
+    pub r#type: String,
+    pub isolated_symbol: Option<String>,
+}
+
+impl SignedRequest<Margin> for InterestHistoryRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/sapi/v1/margin/interestHistory";
+    type Response = InterestHistoryResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct CreateListenKeyRequest {}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateIsolatedListenKeyRequest<'a> {
+    pub symbol: Cow<'a, str>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CreateListenKeyResponse {
+    pub listen_key: String,
+}
+
+impl KeyedRequest<Margin> for CreateListenKeyRequest {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/sapi/v1/userDataStream";
+    type Response = CreateListenKeyResponse;
+}
+
+impl KeyedRequest<Margin> for CreateIsolatedListenKeyRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/sapi/v1/userDataStream/isolated";
+    type Response = CreateListenKeyResponse;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeepAliveListenKeyRequest<'a> {
+    pub listen_key: Cow<'a, str>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeepAliveIsolatedListenKeyRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    pub listen_key: Cow<'a, str>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct KeepAliveListenKeyResponse {}
+
+impl KeyedRequest<Margin> for KeepAliveListenKeyRequest<'_> {
+    const METHOD: Method = Method::PUT;
+    const ENDPOINT: &'static str = "/sapi/v1/userDataStream";
+    type Response = KeepAliveListenKeyResponse;
+}
+
+impl KeyedRequest<Margin> for KeepAliveIsolatedListenKeyRequest<'_> {
+    const METHOD: Method = Method::PUT;
+    const ENDPOINT: &'static str = "/sapi/v1/userDataStream/isolated";
+    type Response = KeepAliveListenKeyResponse;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloseListenKeyRequest<'a> {
+    pub listen_key: Cow<'a, str>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloseIsolatedListenKeyRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    pub listen_key: Cow<'a, str>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CloseListenKeyResponse {}
+
+impl KeyedRequest<Margin> for CloseListenKeyRequest<'_> {
+    const METHOD: Method = Method::DELETE;
+    const ENDPOINT: &'static str = "/sapi/v1/userDataStream";
+    type Response = CloseListenKeyResponse;
+}
+
+impl KeyedRequest<Margin> for CloseIsolatedListenKeyRequest<'_> {
+    const METHOD: Method = Method::DELETE;
+    const ENDPOINT: &'static str = "/sapi/v1/userDataStream/isolated";
+    type Response = CloseListenKeyResponse;
+}
+
+// Binance sends `marginBuyBorrowAmount` either as a string or as a bare number
+pub(crate) fn string_or_number<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrNumber {
+        String(String),
+        Number(serde_json::Number),
+    }
+
+    Ok(
+        Option::<StringOrNumber>::deserialize(deserializer)?.map(|v| match v {
+            StringOrNumber::String(s) => s,
+            StringOrNumber::Number(n) => n.to_string(),
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESULT: &str = r#"{"symbol":"BTCUSDT","orderId":28,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP","transactTime":1507725176595,"price":"1","origQty":"10","executedQty":"10","cummulativeQuoteQty":"10","status":"FILLED","timeInForce":"GTC","type":"MARKET","side":"SELL","isIsolated":true,"selfTradePreventionMode":"NONE""#;
+
+    fn result(rest: &str) -> String {
+        format!("{RESULT}{rest}}}")
+    }
+
+    #[test]
+    fn test_string_or_number() {
+        let borrowed = |rest: &str| {
+            serde_json::from_str::<NewOrderResultResponse>(&result(rest))
+                .unwrap()
+                .margin_buy_borrow_amount
+        };
+        assert_eq!(
+            borrowed(r#","marginBuyBorrowAmount":"5.5","marginBuyBorrowAsset":"BTC""#).as_deref(),
+            Some("5.5")
+        );
+        assert_eq!(
+            borrowed(r#","marginBuyBorrowAmount":5,"marginBuyBorrowAsset":"BTC""#).as_deref(),
+            Some("5")
+        );
+        assert_eq!(borrowed(""), None);
+        assert_eq!(borrowed(r#","marginBuyBorrowAmount":null"#), None);
+    }
+
+    #[test]
+    fn test_new_order_response_variants() {
+        let parse = |json: &str| serde_json::from_str::<NewOrderResponse>(json).unwrap();
+        let full = parse(&result(
+            r#","fills":[{"price":"4000","qty":"1","commission":"4","commissionAsset":"USDT","tradeId":1}]"#,
+        ));
+        assert!(matches!(full, NewOrderResponse::Full(ref full) if full.fills.len() == 1));
+        assert!(matches!(parse(&result("")), NewOrderResponse::Result(_)));
+        assert!(matches!(
+            parse(
+                r#"{"symbol":"BTCUSDT","orderId":28,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP","isIsolated":true,"transactTime":1507725176595}"#
+            ),
+            NewOrderResponse::Ack(_)
+        ));
+    }
+
+    #[test]
+    fn test_response_round_trip() {
+        use crate::response::tests::assert_round_trip;
+        assert_round_trip::<NewOrderResponse>(&result(
+            r#","marginBuyBorrowAmount":"5","marginBuyBorrowAsset":"BTC","fills":[{"price":"4000","qty":"1","commission":"4","commissionAsset":"USDT","tradeId":1}]"#,
+        ));
+        assert_round_trip::<NewOrderResponse>(
+            r#"{"symbol":"BTCUSDT","orderId":28,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP","isIsolated":true,"transactTime":1507725176595}"#,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_paper_client_refuses_borrows() {
+        use crate::{
+            client::{BinanceClient, ClientConfig, Environment},
+            errors::RequestError,
+            validation::tests::param,
+        };
+        let config = ClientConfig::environment(Environment::Testnet);
+        let client = BinanceClient::margin().with_config(config);
+        let req = BorrowRepayRequest {
+            asset: "BTC".into(),
+            is_isolated: None,
+            symbol: None,
+            amount: param("1").unwrap(),
+            r#type: "BORROW".into(),
+            recv_window: None,
+            timestamp: 0,
+        };
+        let res = client.signed_request(&req, "key", "secret").await;
+        assert!(matches!(res, Err(RequestError::PaperMode)));
+    }
+}
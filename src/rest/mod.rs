@@ -1,9 +1,13 @@
 pub mod coinm;
+pub mod filters;
+pub mod margin;
+pub mod options;
+pub mod portfolio_margin;
 pub mod spot;
 pub mod usdm;
 
 use crate::{
-    client::BinanceClient,
+    client::{BinanceClient, MarketUrls, RestMarket},
     errors::{RequestError, ResponseError},
     response::Response,
 };
@@ -14,31 +18,36 @@ use reqwest::{
     header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT},
     Method,
 };
-use serde::{de::DeserializeOwned, Serialize};
+use serde::{de::DeserializeOwned, ser::Error, Serialize, Serializer};
 use sha2::Sha256;
 
-pub trait PublicRequest<T>: Serialize + Clone + Copy {
+pub trait PublicRequest<T: RestMarket>: Serialize + Clone {
     const ENDPOINT: &'static str;
     const METHOD: Method;
     type Response: DeserializeOwned + Clone;
 }
 
-pub trait KeyedRequest<T>: Serialize + Clone + Copy {
+pub trait KeyedRequest<T: RestMarket>: Serialize + Clone {
     const ENDPOINT: &'static str;
     const METHOD: Method;
     type Response: DeserializeOwned + Clone;
 }
 
-pub trait SignedRequest<T>: Serialize + Clone + Copy {
+pub trait SignedRequest<T: RestMarket>: Serialize + Clone {
     const ENDPOINT: &'static str;
     const METHOD: Method;
+    // Places, modifies or cancels orders, which paper clients don't do on production
+    const ORDER_ENTRY: bool = false;
+    // Validated but never executed, like test orders. Paper clients send no other
+    // signed request to production unless it's a GET.
+    const DRY_RUN: bool = false;
     type Response: DeserializeOwned + Clone;
 
     fn timestamp(&self) -> u64;
     fn recv_window(&self) -> u64;
 }
 
-impl<T> BinanceClient<T> {
+impl<T: RestMarket + MarketUrls> BinanceClient<T> {
     pub async fn request<R: PublicRequest<T>>(
         &self,
         req: &R,
@@ -88,19 +97,20 @@ impl<T> BinanceClient<T> {
         Ok(handle_response(resp).await?)
     }
 
-    pub async fn signed_request<R: PublicRequest<T>>(
+    pub async fn signed_request<R: SignedRequest<T>>(
         &self,
         req: &R,
         api_key: &str,
         api_secret: &str,
     ) -> Result<Response<R::Response>, RequestError> {
+        let mutating = R::ORDER_ENTRY || (R::METHOD != Method::GET && !R::DRY_RUN);
+        if self.config.paper && mutating && self.config.targets_production_rest() {
+            return Err(RequestError::PaperMode);
+        }
+
         let base = &self.config.rest_base_url;
         let endpoint = R::ENDPOINT;
-        let mut params = serde_qs::to_string(req).unwrap();
-
-        let signature = signature(&params, api_secret)?;
-        params.push_str(&format!("&signature={}", signature));
-
+        let params = signed_query(req, api_secret)?;
         let url = format!("{base}{endpoint}?{params}");
 
         let mut custom_headers = HeaderMap::new();
@@ -121,6 +131,14 @@ impl<T> BinanceClient<T> {
     }
 }
 
+// The signature covers the query as sent, lists included in their encoded form
+fn signed_query(req: &impl Serialize, secret: &str) -> Result<String, InvalidLength> {
+    let mut params = serde_qs::to_string(req).unwrap();
+    let signature = signature(&params, secret)?;
+    params.push_str(&format!("&signature={}", signature));
+    Ok(params)
+}
+
 fn signature(params: &str, secret: &str) -> Result<String, InvalidLength> {
     // Signature: hex(HMAC_SHA256(queries + data))
     let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())?;
@@ -149,3 +167,140 @@ async fn handle_response<O: DeserializeOwned>(
         })
     }
 }
+
+// Name of the newtype json_array wraps its string in. Query serializers see
+// through it, the WS API serializer looks for it to send the array itself.
+pub(crate) const JSON_ARRAY: &str = "$binance_async_api::JsonArray";
+
+// Serializes a list parameter the way Binance takes it in a query, as a JSON
+// array in a string: symbols=["BTCUSDT","ETHUSDT"]. serde_qs would send
+// symbols[0]=BTCUSDT&symbols[1]=ETHUSDT, which Binance rejects. The WS API
+// takes the array itself.
+pub fn json_array<T, S>(list: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize + ?Sized,
+    S: Serializer,
+{
+    serializer.serialize_newtype_struct(JSON_ARRAY, &JsonText(list))
+}
+
+struct JsonText<'a, T: ?Sized>(&'a T);
+
+impl<T: Serialize + ?Sized> Serialize for JsonText<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let json = serde_json::to_string(self.0).map_err(S::Error::custom)?;
+        serializer.serialize_str(&json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        enums::{OrderType, Side},
+        validation::tests::param,
+    };
+
+    // Binance's example secret
+    const SECRET: &str = "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j";
+
+    fn cancel_batch_request() -> coinm::CancelBatchOrdersRequest<'static> {
+        coinm::CancelBatchOrdersRequest {
+            symbol: "BTCUSD_PERP".into(),
+            order_id_list: Some(vec![1, 2]),
+            orig_client_order_id_list: None,
+            recv_window: None,
+            timestamp: 1,
+        }
+    }
+
+    #[test]
+    fn test_json_array_query() {
+        let req = spot::ExchangeInfoRequest {
+            symbol: None,
+            symbols: Some(vec!["BTCUSDT".into(), "ETHUSDT".into()]),
+            permissions: None,
+            show_permission_sets: None,
+            symbol_status: None,
+        };
+        assert_eq!(
+            serde_qs::to_string(&req).unwrap(),
+            "symbols=%5B%22BTCUSDT%22%2C%22ETHUSDT%22%5D"
+        );
+
+        // And the spot tickers, for several symbols at once
+        let symbols = || Some(vec!["BTCUSDT".into(), "ETHUSDT".into()]);
+        let tickers = [
+            serde_qs::to_string(&spot::Ticker24hrRequest {
+                symbol: None,
+                symbols: symbols(),
+                r#type: None,
+            }),
+            serde_qs::to_string(&spot::RollingWindowTickerRequest {
+                symbol: None,
+                symbols: symbols(),
+                window_size: None,
+                r#type: None,
+            }),
+            serde_qs::to_string(&spot::PriceTickerRequest {
+                symbol: None,
+                symbols: symbols(),
+            }),
+            serde_qs::to_string(&spot::BookTickerRequest {
+                symbol: None,
+                symbols: symbols(),
+            }),
+        ];
+        for query in tickers {
+            assert_eq!(
+                query.unwrap(),
+                "symbols=%5B%22BTCUSDT%22%2C%22ETHUSDT%22%5D"
+            );
+        }
+
+        assert_eq!(
+            serde_qs::to_string(&cancel_batch_request()).unwrap(),
+            "symbol=BTCUSD_PERP&orderIdList=%5B1%2C2%5D&timestamp=1"
+        );
+
+        let req = options::NewBatchOrdersRequest {
+            orders: vec![options::BatchOrder {
+                symbol: "BTC-240628-60000-C".into(),
+                side: Side::Buy,
+                r#type: OrderType::Limit,
+                quantity: param("0.1").unwrap(),
+                price: param("5"),
+                time_in_force: None,
+                reduce_only: None,
+                post_only: None,
+                new_order_resp_type: None,
+                client_order_id: None,
+                is_mmp: None,
+            }],
+            recv_window: None,
+            timestamp: 1,
+        };
+        assert_eq!(
+            serde_qs::to_string(&req).unwrap(),
+            "orders=%5B%7B%22symbol%22%3A%22BTC-240628-60000-C%22%2C%22side%22%3A%22BUY%22%2C\
+             %22type%22%3A%22LIMIT%22%2C%22quantity%22%3A%220.1%22%2C%22price%22%3A%225%22%7D%5D\
+             &timestamp=1"
+        );
+
+        // Any other serializer gets the same string
+        assert_eq!(
+            serde_json::to_string(&cancel_batch_request()).unwrap(),
+            r#"{"symbol":"BTCUSD_PERP","orderIdList":"[1,2]","timestamp":1}"#
+        );
+    }
+
+    #[test]
+    fn test_signed_query() {
+        // Signed over the percent encoded list, as sent
+        assert_eq!(
+            signed_query(&cancel_batch_request(), SECRET).unwrap(),
+            "symbol=BTCUSD_PERP&orderIdList=%5B1%2C2%5D&timestamp=1\
+             &signature=7ba09ba4842535b8087233c228d094588b454cf634fa28f1f2fcfc40fc8685c3"
+        );
+    }
+}
@@ -0,0 +1,598 @@
+use crate::{
+    client::Options,
+    decimal::{Decimal, DecimalParam},
+    enums::{OrderType, PositionSide, Side, TimeInForce},
+    errors::ContentError,
+};
+
+use super::{json_array, PublicRequest, SignedRequest};
+use binance_async_api_macros::wire_names;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+pub use super::usdm::{BookLevel, RateLimit, SymbolFilter};
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct ExchangeInfoRequest;
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ExchangeInfoResponse {
+    pub timezone: String,
+    pub server_time: u64,
+    pub option_contracts: Vec<OptionContract>,
+    pub option_assets: Vec<OptionAsset>,
+    pub option_symbols: Vec<Market>,
+    pub rate_limits: Vec<RateLimit>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OptionContract {
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub underlying: String,
+    pub settle_asset: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OptionAsset {
+    pub name: String,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Market {
+    pub symbol: String,
+    pub expiry_date: u64,
+    pub filters: Vec<SymbolFilter>,
+    pub side: String, // CALL or PUT
+    pub strike_price: Decimal,
+    pub underlying: String,
+    pub unit: u64, // contract unit, in underlying
+    pub maker_fee_rate: String,
+    pub taker_fee_rate: String,
+    pub min_qty: Decimal,
+    pub max_qty: Decimal,
+    pub initial_margin: Decimal,
+    pub maintenance_margin: Decimal,
+    pub min_initial_margin: Decimal,
+    pub min_maintenance_margin: Decimal,
+    pub price_scale: u64,
+    pub quantity_scale: u64,
+    pub quote_asset: String,
+}
+
+impl PublicRequest<Options> for ExchangeInfoRequest {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/eapi/v1/exchangeInfo";
+    type Response = ExchangeInfoResponse;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MarkPriceRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MarkPriceResponse {
+    pub symbol: String,
+    pub mark_price: Decimal,
+    #[wire = "bidIV"]
+    pub bid_iv: String,
+    #[wire = "askIV"]
+    pub ask_iv: String,
+    #[wire = "markIV"]
+    pub mark_iv: String,
+    pub delta: String,
+    pub theta: String,
+    pub gamma: String,
+    pub vega: String,
+    pub high_price_limit: Decimal,
+    pub low_price_limit: Decimal,
+    pub risk_free_interest: Decimal,
+}
+
+impl PublicRequest<Options> for MarkPriceRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/eapi/v1/mark";
+    type Response = Vec<MarkPriceResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderBookRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrderBookResponse {
+    #[wire = "T"]
+    pub transaction_time: u64,
+    #[wire = "u"]
+    pub update_id: u64,
+    pub bids: Vec<BookLevel>,
+    pub asks: Vec<BookLevel>,
+}
+
+impl PublicRequest<Options> for OrderBookRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/eapi/v1/depth";
+    type Response = OrderBookResponse;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KlinesRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    pub interval: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 1500
+}
+
+// Unlike the other markets, option klines are objects
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct KlineResponse {
+    pub open_time: u64,
+    pub close_time: u64,
+    pub interval: String,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    pub volume: Decimal,
+    pub amount: Decimal,
+    pub trade_count: u64,
+    pub taker_volume: Decimal,
+    pub taker_amount: Decimal,
+}
+
+impl PublicRequest<Options> for KlinesRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/eapi/v1/klines";
+    type Response = Vec<KlineResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexPriceRequest<'a> {
+    pub underlying: Cow<'a, str>, // e.g. BTCUSDT
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IndexPriceResponse {
+    pub time: u64,
+    pub index_price: Decimal,
+}
+
+impl PublicRequest<Options> for IndexPriceRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/eapi/v1/index";
+    type Response = IndexPriceResponse;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestRequest<'a> {
+    pub underlying_asset: Cow<'a, str>, // e.g. BTC
+    pub expiration: Cow<'a, str>,       // YYMMDD
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OpenInterestResponse {
+    pub symbol: String,
+    pub sum_open_interest: Decimal,
+    pub sum_open_interest_usd: String,
+    pub timestamp: String,
+}
+
+impl PublicRequest<Options> for OpenInterestRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/eapi/v1/openInterest";
+    type Response = Vec<OpenInterestResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    pub side: Side,
+    pub r#type: OrderType, // LIMIT only
+    pub quantity: DecimalParam<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<Cow<'a, str>>, // ACK or RESULT
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_mmp: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+// ACK responses only carry the fields up to `type`
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrderResponse {
+    pub order_id: u64,
+    pub symbol: String,
+    pub price: Decimal,
+    pub quantity: Decimal,
+    pub side: Side,
+    pub r#type: OrderType,
+    pub create_time: Option<u64>,
+    pub client_order_id: Option<String>,
+    pub executed_qty: Option<Decimal>,
+    pub fee: Option<Decimal>,
+    pub time_in_force: Option<TimeInForce>,
+    pub reduce_only: Option<bool>,
+    pub post_only: Option<bool>,
+    pub update_time: Option<u64>,
+    pub status: Option<String>,
+    pub avg_price: Option<Decimal>,
+    pub price_scale: Option<u64>,
+    pub quantity_scale: Option<u64>,
+    pub option_side: Option<String>,
+    pub quote_asset: Option<String>,
+    pub mmp: Option<bool>,
+}
+
+impl SignedRequest<Options> for NewOrderRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/eapi/v1/order";
+    const ORDER_ENTRY: bool = true;
+    type Response = OrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+// Each order of a batch succeeds or fails on its own
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum BatchOrderResponse {
+    Order(OrderResponse),
+    Error(ContentError),
+}
+
+// Same parameters as `NewOrderRequest`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchOrder<'a> {
+    pub symbol: Cow<'a, str>,
+    pub side: Side,
+    pub r#type: OrderType, // LIMIT only
+    pub quantity: DecimalParam<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<Cow<'a, str>>, // ACK or RESULT
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_mmp: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewBatchOrdersRequest<'a> {
+    #[serde(serialize_with = "json_array")]
+    pub orders: Vec<BatchOrder<'a>>, // at most 10
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<Options> for NewBatchOrdersRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/eapi/v1/batchOrders";
+    const ORDER_ENTRY: bool = true;
+    type Response = Vec<BatchOrderResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<Options> for CancelOrderRequest<'_> {
+    const METHOD: Method = Method::DELETE;
+    const ENDPOINT: &'static str = "/eapi/v1/order";
+    const ORDER_ENTRY: bool = true;
+    type Response = OrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelBatchOrdersRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "json_array")]
+    pub order_ids: Option<Vec<u64>>, // at most 10
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "json_array")]
+    pub client_order_ids: Option<Vec<Cow<'a, str>>>, // at most 10
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<Options> for CancelBatchOrdersRequest<'_> {
+    const METHOD: Method = Method::DELETE;
+    const ENDPOINT: &'static str = "/eapi/v1/batchOrders";
+    const ORDER_ENTRY: bool = true;
+    type Response = Vec<BatchOrderResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllOpenOrdersRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CodeResponse {
+    pub code: i64,
+    pub msg: String,
+}
+
+impl SignedRequest<Options> for CancelAllOpenOrdersRequest<'_> {
+    const METHOD: Method = Method::DELETE;
+    const ENDPOINT: &'static str = "/eapi/v1/allOpenOrders";
+    const ORDER_ENTRY: bool = true;
+    type Response = CodeResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PositionResponse {
+    pub symbol: String,
+    pub side: Side,
+    pub quantity: Decimal,
+    pub reducible_qty: Decimal,
+    pub entry_price: Decimal,
+    pub mark_price: Decimal,
+    pub mark_value: Decimal,
+    pub ror: String,
+    #[wire = "unrealizedPNL"]
+    pub unrealized_pnl: Decimal,
+    pub strike_price: Decimal,
+    pub position_cost: String,
+    pub expiry_date: u64,
+    pub price_scale: u64,
+    pub quantity_scale: u64,
+    pub option_side: String,
+    pub quote_asset: String,
+}
+
+impl SignedRequest<Options> for PositionRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/eapi/v1/position";
+    type Response = Vec<PositionResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformationRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AccountInformationResponse {
+    #[wire = "asset"]
+    pub assets: Vec<AccountAsset>,
+    #[wire = "greek"]
+    pub greeks: Vec<AccountGreeks>,
+    pub time: u64,
+    pub risk_level: String, // NORMAL, MEDIUM, HIGH or REDUCE_ONLY
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AccountAsset {
+    pub asset: String,
+    pub margin_balance: Decimal,
+    pub equity: Decimal,
+    pub available: Decimal,
+    pub locked: Decimal,
+    #[wire = "unrealizedPNL"]
+    pub unrealized_pnl: Decimal,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AccountGreeks {
+    pub underlying: String,
+    pub delta: String,
+    pub gamma: String,
+    pub theta: String,
+    pub vega: String,
+}
+
+impl SignedRequest<Options> for AccountInformationRequest {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/eapi/v1/account";
+    type Response = AccountInformationResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseRecordRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 1000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ExerciseRecordResponse {
+    pub id: String,
+    pub currency: String,
+    pub symbol: String,
+    pub exercise_price: Decimal,
+    pub mark_price: Decimal,
+    pub quantity: Decimal,
+    pub amount: Decimal,
+    pub fee: Decimal,
+    pub create_date: u64,
+    pub price_scale: u64,
+    pub quantity_scale: u64,
+    pub option_side: String,
+    pub position_side: PositionSide,
+    pub quote_asset: String,
+}
+
+impl SignedRequest<Options> for ExerciseRecordRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/eapi/v1/exerciseRecord";
+    type Response = Vec<ExerciseRecordResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::BinanceClient;
+
+    #[tokio::test]
+    async fn test_exchange_info_request() {
+        let client = BinanceClient::options();
+        let req = ExchangeInfoRequest;
+        let res = client.request(&req).await.unwrap();
+        assert!(res.status.is_success());
+    }
+
+    #[tokio::test]
+    async fn test_index_price_request() {
+        let client = BinanceClient::options();
+        let req = IndexPriceRequest {
+            underlying: "BTCUSDT".into(),
+        };
+        let res = client.request(&req).await.unwrap();
+        assert!(res.status.is_success());
+    }
+
+    #[test]
+    fn test_response_round_trip() {
+        use crate::response::tests::assert_round_trip;
+        assert_round_trip::<Vec<MarkPriceResponse>>(
+            r#"[{"symbol":"BTC-200730-9000-C","markPrice":"1343.2883","bidIV":"0","askIV":"0","markIV":"1.99","delta":"0.55937","theta":"3739.82","gamma":"0.000004","vega":"978.58","highPriceLimit":"1618.241","lowPriceLimit":"1068.3356","riskFreeInterest":"0.1"}]"#,
+        );
+        assert_round_trip::<OrderBookResponse>(
+            r#"{"T":1589436922972,"u":37461,"bids":[["1000","0.9"]],"asks":[["1100","0.1"]]}"#,
+        );
+        assert_round_trip::<Vec<BatchOrderResponse>>(
+            r#"[{"orderId":4611875134427365377,"symbol":"BTC-200730-9000-C","price":"100","quantity":"1","executedQty":"0","fee":"0","side":"BUY","type":"LIMIT","timeInForce":"GTC","reduceOnly":false,"postOnly":false,"createTime":1592465880683,"updateTime":1566818724722,"status":"ACCEPTED","avgPrice":"0","clientOrderId":"","priceScale":2,"quantityScale":2,"optionSide":"CALL","quoteAsset":"USDT","mmp":false},{"code":-4001,"msg":"Invalid price."}]"#,
+        );
+        assert_round_trip::<Vec<PositionResponse>>(
+            r#"[{"entryPrice":"1000","symbol":"BTC-200730-9000-C","side":"SHORT","quantity":"-0.1","reducibleQty":"0","markValue":"105","ror":"-0.05","unrealizedPNL":"-5","markPrice":"1050","strikePrice":"9000","positionCost":"1000","expiryDate":1593511200000,"priceScale":2,"quantityScale":2,"optionSide":"CALL","quoteAsset":"USDT"}]"#,
+        );
+    }
+}
@@ -0,0 +1,715 @@
+use crate::{
+    client::PortfolioMargin,
+    decimal::{Decimal, DecimalParam},
+    enums::{
+        OrderStatus, OrderType, PositionSide, PriceMatch, SelfTradePreventionMode, Side,
+        TimeInForce,
+    },
+};
+
+use super::{margin::string_or_number, KeyedRequest, SignedRequest};
+use binance_async_api_macros::wire_names;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+pub use super::spot::Fill;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UmNewOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    pub side: Side,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_side: Option<PositionSide>,
+    pub r#type: OrderType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<Cow<'a, str>>, // ACK or RESULT
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_match: Option<PriceMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub good_till_date: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UmOrderResponse {
+    pub client_order_id: String,
+    pub cum_qty: Decimal,
+    pub cum_quote: Decimal,
+    pub executed_qty: Decimal,
+    pub order_id: u64,
+    pub avg_price: Decimal,
+    pub orig_qty: Decimal,
+    pub price: Decimal,
+    pub reduce_only: bool,
+    pub side: Side,
+    pub position_side: PositionSide,
+    pub status: OrderStatus,
+    pub symbol: String,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+    pub good_till_date: u64,
+    pub update_time: u64,
+    pub price_match: PriceMatch,
+}
+
+impl SignedRequest<PortfolioMargin> for UmNewOrderRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/papi/v1/um/order";
+    const ORDER_ENTRY: bool = true;
+    type Response = UmOrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UmCancelOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<PortfolioMargin> for UmCancelOrderRequest<'_> {
+    const METHOD: Method = Method::DELETE;
+    const ENDPOINT: &'static str = "/papi/v1/um/order";
+    const ORDER_ENTRY: bool = true;
+    type Response = UmOrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CmNewOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    pub side: Side,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_side: Option<PositionSide>,
+    pub r#type: OrderType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<DecimalParam<'a>>, // in contracts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<Cow<'a, str>>, // ACK or RESULT
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CmOrderResponse {
+    pub client_order_id: String,
+    pub cum_qty: Decimal,
+    pub cum_base: Decimal,
+    pub executed_qty: Decimal,
+    pub order_id: u64,
+    pub avg_price: Decimal,
+    pub orig_qty: Decimal,
+    pub price: Decimal,
+    pub reduce_only: bool,
+    pub side: Side,
+    pub position_side: PositionSide,
+    pub status: OrderStatus,
+    pub symbol: String,
+    pub pair: String,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub update_time: u64,
+}
+
+impl SignedRequest<PortfolioMargin> for CmNewOrderRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/papi/v1/cm/order";
+    const ORDER_ENTRY: bool = true;
+    type Response = CmOrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CmCancelOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<PortfolioMargin> for CmCancelOrderRequest<'_> {
+    const METHOD: Method = Method::DELETE;
+    const ENDPOINT: &'static str = "/papi/v1/cm/order";
+    const ORDER_ENTRY: bool = true;
+    type Response = CmOrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginNewOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    pub side: Side,
+    pub r#type: OrderType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_order_qty: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<Cow<'a, str>>, // ACK, RESULT or FULL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iceberg_qty: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side_effect_type: Option<Cow<'a, str>>, // NO_SIDE_EFFECT, MARGIN_BUY or AUTO_REPAY
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_repay_at_cancel: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MarginNewOrderAckResponse {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub transact_time: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MarginNewOrderResultResponse {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub transact_time: u64,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub side: Side,
+    #[serde(default, deserialize_with = "string_or_number")]
+    pub margin_buy_borrow_amount: Option<String>,
+    pub margin_buy_borrow_asset: Option<String>,
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MarginNewOrderFullResponse {
+    #[serde(flatten)]
+    pub result: MarginNewOrderResultResponse,
+    pub fills: Vec<Fill>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum MarginNewOrderResponse {
+    Full(MarginNewOrderFullResponse),
+    Result(MarginNewOrderResultResponse),
+    Ack(MarginNewOrderAckResponse),
+}
+
+impl SignedRequest<PortfolioMargin> for MarginNewOrderRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/papi/v1/margin/order";
+    const ORDER_ENTRY: bool = true;
+    type Response = MarginNewOrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginCancelOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MarginCancelOrderResponse {
+    pub symbol: String,
+    pub order_id: u64,
+    pub orig_client_order_id: String,
+    pub client_order_id: String,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub side: Side,
+}
+
+impl SignedRequest<PortfolioMargin> for MarginCancelOrderRequest<'_> {
+    const METHOD: Method = Method::DELETE;
+    const ENDPOINT: &'static str = "/papi/v1/margin/order";
+    const ORDER_ENTRY: bool = true;
+    type Response = MarginCancelOrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+// Without `asset`, Binance returns every asset of the account
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BalanceResponse {
+    pub asset: String,
+    pub total_wallet_balance: Decimal,
+    pub cross_margin_asset: String,
+    pub cross_margin_borrowed: Decimal,
+    pub cross_margin_free: Decimal,
+    pub cross_margin_interest: Decimal,
+    pub cross_margin_locked: Decimal,
+    pub um_wallet_balance: Decimal,
+    #[wire = "umUnrealizedPNL"]
+    pub um_unrealized_pnl: Decimal,
+    pub cm_wallet_balance: Decimal,
+    #[wire = "cmUnrealizedPNL"]
+    pub cm_unrealized_pnl: Decimal,
+    pub update_time: u64,
+    pub negative_balance: Decimal,
+}
+
+// A single balance when `asset` is set
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum BalancesResponse {
+    One(BalanceResponse),
+    All(Vec<BalanceResponse>),
+}
+
+impl SignedRequest<PortfolioMargin> for BalanceRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/papi/v1/balance";
+    type Response = BalancesResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformationRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AccountInformationResponse {
+    #[wire = "uniMMR"]
+    pub uni_mmr: String,
+    pub account_equity: Decimal,
+    pub actual_equity: Decimal,
+    pub account_initial_margin: Decimal,
+    pub account_maint_margin: Decimal,
+    pub account_status: String,
+    pub virtual_max_withdraw_amount: Decimal,
+    pub total_available_balance: Decimal,
+    pub total_margin_open_loss: Decimal,
+    pub update_time: u64,
+}
+
+impl SignedRequest<PortfolioMargin> for AccountInformationRequest {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/papi/v1/account";
+    type Response = AccountInformationResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UmPositionRiskRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UmPositionRiskResponse {
+    pub symbol: String,
+    pub position_amt: Decimal,
+    pub entry_price: Decimal,
+    pub mark_price: Decimal,
+    pub un_realized_profit: Decimal,
+    pub liquidation_price: Decimal,
+    pub leverage: String,
+    pub max_notional_value: Decimal,
+    pub notional: Decimal,
+    pub position_side: PositionSide,
+    pub update_time: u64,
+}
+
+impl SignedRequest<PortfolioMargin> for UmPositionRiskRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/papi/v1/um/positionRisk";
+    type Response = Vec<UmPositionRiskResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CmPositionRiskRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin_asset: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CmPositionRiskResponse {
+    pub symbol: String,
+    pub position_amt: Decimal, // in contracts
+    pub entry_price: Decimal,
+    pub mark_price: Decimal,
+    pub un_realized_profit: Decimal,
+    pub liquidation_price: Decimal,
+    pub leverage: String,
+    pub max_qty: Decimal,
+    pub notional_value: Decimal,
+    pub position_side: PositionSide,
+    pub update_time: u64,
+}
+
+impl SignedRequest<PortfolioMargin> for CmPositionRiskRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/papi/v1/cm/positionRisk";
+    type Response = Vec<CmPositionRiskResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeAutoRepayRequest<'a> {
+    pub auto_repay: Cow<'a, str>, // true or false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MsgResponse {
+    pub msg: String,
+}
+
+impl SignedRequest<PortfolioMargin> for ChangeAutoRepayRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/papi/v1/repay-futures-switch";
+    type Response = MsgResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoRepayStatusRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AutoRepayStatusResponse {
+    pub auto_repay: bool,
+}
+
+impl SignedRequest<PortfolioMargin> for AutoRepayStatusRequest {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/papi/v1/repay-futures-switch";
+    type Response = AutoRepayStatusResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepayFuturesNegativeBalanceRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<PortfolioMargin> for RepayFuturesNegativeBalanceRequest {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/papi/v1/repay-futures-negative-balance";
+    type Response = MsgResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct CreateListenKeyRequest {}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CreateListenKeyResponse {
+    pub listen_key: String,
+}
+
+impl KeyedRequest<PortfolioMargin> for CreateListenKeyRequest {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/papi/v1/listenKey";
+    type Response = CreateListenKeyResponse;
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct KeepAliveListenKeyRequest {}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct KeepAliveListenKeyResponse {}
+
+impl KeyedRequest<PortfolioMargin> for KeepAliveListenKeyRequest {
+    const METHOD: Method = Method::PUT;
+    const ENDPOINT: &'static str = "/papi/v1/listenKey";
+    type Response = KeepAliveListenKeyResponse;
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct CloseListenKeyRequest {}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CloseListenKeyResponse {}
+
+impl KeyedRequest<PortfolioMargin> for CloseListenKeyRequest {
+    const METHOD: Method = Method::DELETE;
+    const ENDPOINT: &'static str = "/papi/v1/listenKey";
+    type Response = CloseListenKeyResponse;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESULT: &str = r#"{"symbol":"BTCUSDT","orderId":28,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP","transactTime":1507725176595,"price":"1","origQty":"10","executedQty":"10","cummulativeQuoteQty":"10","status":"FILLED","timeInForce":"GTC","type":"MARKET","side":"SELL","marginBuyBorrowAmount":5,"marginBuyBorrowAsset":"BTC","selfTradePreventionMode":"NONE""#;
+    const FILLS: &str = r#","fills":[{"price":"4000","qty":"1","commission":"4","commissionAsset":"USDT","tradeId":1}]"#;
+    const ACK: &str = r#"{"symbol":"BTCUSDT","orderId":28,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP","transactTime":1507725176595}"#;
+
+    #[test]
+    fn test_margin_new_order_response_variants() {
+        let parse = |json: &str| serde_json::from_str::<MarginNewOrderResponse>(json).unwrap();
+        let MarginNewOrderResponse::Full(full) = parse(&format!("{RESULT}{FILLS}}}")) else {
+            panic!("not a full response");
+        };
+        assert_eq!(full.fills.len(), 1);
+        assert_eq!(full.result.margin_buy_borrow_amount.as_deref(), Some("5"));
+        assert!(matches!(
+            parse(&format!("{RESULT}}}")),
+            MarginNewOrderResponse::Result(_)
+        ));
+        assert!(matches!(parse(ACK), MarginNewOrderResponse::Ack(_)));
+    }
+
+    #[test]
+    fn test_balance_request() {
+        let balance = r#"{"asset":"USDT","totalWalletBalance":"122607.35137903","crossMarginAsset":"92.27530794","crossMarginBorrowed":"10","crossMarginFree":"100","crossMarginInterest":"0.1","crossMarginLocked":"3","umWalletBalance":"0.1","umUnrealizedPNL":"23.72469206","cmWalletBalance":"23.72469206","cmUnrealizedPNL":"0","updateTime":1617939110373,"negativeBalance":"0"}"#;
+        assert!(matches!(
+            serde_json::from_str(balance).unwrap(),
+            BalancesResponse::One(_)
+        ));
+        assert!(matches!(
+            serde_json::from_str(&format!("[{balance}]")).unwrap(),
+            BalancesResponse::All(balances) if balances.len() == 1
+        ));
+
+        let request = BalanceRequest {
+            asset: Some("USDT".into()),
+            recv_window: None,
+            timestamp: 1,
+        };
+        assert_eq!(
+            serde_qs::to_string(&request).unwrap(),
+            "asset=USDT&timestamp=1"
+        );
+    }
+
+    #[test]
+    fn test_response_round_trip() {
+        use crate::response::tests::assert_round_trip;
+        assert_round_trip::<UmOrderResponse>(
+            r#"{"clientOrderId":"testOrder","cumQty":"0","cumQuote":"0","executedQty":"0","orderId":22542179,"avgPrice":"0","origQty":"10","price":"0","reduceOnly":false,"side":"BUY","positionSide":"SHORT","status":"NEW","symbol":"BTCUSDT","timeInForce":"GTD","type":"LIMIT","selfTradePreventionMode":"NONE","goodTillDate":1693207680000,"updateTime":1566818724722,"priceMatch":"NONE"}"#,
+        );
+        assert_round_trip::<CmOrderResponse>(
+            r#"{"clientOrderId":"testOrder","cumQty":"0","cumBase":"0","executedQty":"0","orderId":22542179,"avgPrice":"0","origQty":"10","price":"0","reduceOnly":false,"side":"BUY","positionSide":"SHORT","status":"NEW","symbol":"BTCUSD_200925","pair":"BTCUSD","timeInForce":"GTC","type":"LIMIT","updateTime":1566818724722}"#,
+        );
+        // `string_or_number` only ever gives the borrowed amount back as a string
+        let result = RESULT.replace(
+            r#""marginBuyBorrowAmount":5"#,
+            r#""marginBuyBorrowAmount":"5""#,
+        );
+        assert_round_trip::<MarginNewOrderResponse>(&format!("{result}{FILLS}}}"));
+        assert_round_trip::<MarginNewOrderResponse>(ACK);
+        assert_round_trip::<AccountInformationResponse>(
+            r#"{"uniMMR":"5167.92171923","accountEquity":"122607.35137903","actualEquity":"73.47428058","accountInitialMargin":"23.72469206","accountMaintMargin":"23.72469206","accountStatus":"NORMAL","virtualMaxWithdrawAmount":"1627523.32459208","totalAvailableBalance":"0","totalMarginOpenLoss":"0","updateTime":1657707212154}"#,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_paper_client_refuses_fund_moves() {
+        use crate::{
+            client::{BinanceClient, ClientConfig, Environment},
+            errors::RequestError,
+        };
+        let config = ClientConfig::environment(Environment::Testnet);
+        let client = BinanceClient::portfolio_margin().with_config(config);
+        let req = ChangeAutoRepayRequest {
+            auto_repay: "false".into(),
+            recv_window: None,
+            timestamp: 0,
+        };
+        let res = client.signed_request(&req, "key", "secret").await;
+        assert!(matches!(res, Err(RequestError::PaperMode)));
+        let req = RepayFuturesNegativeBalanceRequest {
+            recv_window: None,
+            timestamp: 0,
+        };
+        let res = client.signed_request(&req, "key", "secret").await;
+        assert!(matches!(res, Err(RequestError::PaperMode)));
+    }
+}
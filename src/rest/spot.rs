@@ -0,0 +1,1796 @@
+use crate::{
+    client::Spot,
+    decimal::{Decimal, DecimalParam},
+    enums::{
+        OrderStatus, OrderType, RateLimitInterval, RateLimitType, SelfTradePreventionMode, Side,
+        TimeInForce,
+    },
+};
+
+use super::{json_array, KeyedRequest, PublicRequest, SignedRequest};
+use binance_async_api_macros::wire_names;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+pub use super::filters::{ExchangeFilter, RateLimit, SymbolFilter};
+
+// At most one of `symbol`, `symbols` and `permissions`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInfoRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "json_array")]
+    pub symbols: Option<Vec<Cow<'a, str>>>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "json_array")]
+    pub permissions: Option<Vec<Cow<'a, str>>>, // e.g. SPOT, MARGIN
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_permission_sets: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol_status: Option<Cow<'a, str>>, // TRADING, HALT or BREAK
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ExchangeInfoResponse {
+    pub timezone: String,
+    pub server_time: u64,
+    pub rate_limits: Vec<RateLimit>,
+    pub exchange_filters: Vec<ExchangeFilter>,
+    pub symbols: Vec<Market>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Market {
+    pub symbol: String,
+    pub status: String,
+    pub base_asset: String,
+    pub base_asset_precision: u64,
+    pub quote_asset: String,
+    pub quote_precision: u64,
+    pub quote_asset_precision: u64,
+    pub base_commission_precision: u64,
+    pub quote_commission_precision: u64,
+    pub order_types: Vec<OrderType>,
+    pub iceberg_allowed: bool,
+    pub oco_allowed: bool,
+    #[serde(default)]
+    pub oto_allowed: bool,
+    pub quote_order_qty_market_allowed: bool,
+    pub allow_trailing_stop: bool,
+    pub cancel_replace_allowed: bool,
+    #[serde(default)]
+    pub amend_allowed: bool,
+    pub is_spot_trading_allowed: bool,
+    pub is_margin_trading_allowed: bool,
+    pub filters: Vec<SymbolFilter>,
+    pub permissions: Vec<String>,
+    #[serde(default)]
+    pub permission_sets: Vec<Vec<String>>,
+    pub default_self_trade_prevention_mode: SelfTradePreventionMode,
+    pub allowed_self_trade_prevention_modes: Vec<SelfTradePreventionMode>,
+}
+
+impl PublicRequest<Spot> for ExchangeInfoRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/exchangeInfo";
+    type Response = ExchangeInfoResponse;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderBookRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 5000
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrderBookResponse {
+    pub last_update_id: u64,
+    pub bids: Vec<BookLevel>,
+    pub asks: Vec<BookLevel>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BookLevel {
+    pub price: Decimal,
+    pub qty: Decimal,
+}
+
+serialize_as_tuple!(BookLevel { price, qty });
+
+impl PublicRequest<Spot> for OrderBookRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/depth";
+    type Response = OrderBookResponse;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecentTradesRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 1000
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoricalTradesRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 1000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<u64>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TradeResponse {
+    pub id: u64,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub quote_qty: Decimal,
+    pub time: u64,
+    pub is_buyer_maker: bool,
+    pub is_best_match: bool,
+}
+
+impl PublicRequest<Spot> for RecentTradesRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/trades";
+    type Response = Vec<TradeResponse>;
+}
+
+impl PublicRequest<Spot> for HistoricalTradesRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/historicalTrades";
+    type Response = Vec<TradeResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AggTradesRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 1000
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AggTradeResponse {
+    #[wire = "a"]
+    pub id: u64,
+    #[wire = "p"]
+    pub price: Decimal,
+    #[wire = "q"]
+    pub qty: Decimal,
+    #[wire = "f"]
+    pub first_trade_id: u64,
+    #[wire = "l"]
+    pub last_trade_id: u64,
+    #[wire = "T"]
+    pub timestamp: u64,
+    #[wire = "m"]
+    pub buyer_is_maker: bool,
+    #[wire = "M"]
+    pub is_best_match: bool,
+}
+
+impl PublicRequest<Spot> for AggTradesRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/aggTrades";
+    type Response = Vec<AggTradeResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KlinesRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    pub interval: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<Cow<'a, str>>, // e.g. "+08:00", defaults to UTC
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 1000
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UiKlinesRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    pub interval: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<Cow<'a, str>>, // e.g. "+08:00", defaults to UTC
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 1000
+}
+
+// Klines are sent as arrays, in this field order
+#[derive(Debug, Clone, Deserialize)]
+pub struct KlineResponse {
+    pub open_time: u64,
+    pub open_price: Decimal,
+    pub high_price: Decimal,
+    pub low_price: Decimal,
+    pub close_price: Decimal,
+    pub base_asset_volume: Decimal,
+    pub close_time: u64,
+    pub quote_asset_volume: Decimal,
+    pub trade_count: u64,
+    pub taker_buy_base_asset_volume: Decimal,
+    pub taker_buy_quote_asset_volume: Decimal,
+    pub unused: String,
+}
+
+serialize_as_tuple!(KlineResponse {
+    open_time,
+    open_price,
+    high_price,
+    low_price,
+    close_price,
+    base_asset_volume,
+    close_time,
+    quote_asset_volume,
+    trade_count,
+    taker_buy_base_asset_volume,
+    taker_buy_quote_asset_volume,
+    unused
+});
+
+impl PublicRequest<Spot> for KlinesRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/klines";
+    type Response = Vec<KlineResponse>;
+}
+
+impl PublicRequest<Spot> for UiKlinesRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/uiKlines";
+    type Response = Vec<KlineResponse>;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AvgPriceRequest<'a> {
+    pub symbol: Cow<'a, str>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AvgPriceResponse {
+    pub mins: u64,
+    pub price: Decimal,
+    pub close_time: u64,
+}
+
+impl PublicRequest<Spot> for AvgPriceRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/avgPrice";
+    type Response = AvgPriceResponse;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Ticker24hrRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "json_array")]
+    pub symbols: Option<Vec<Cow<'a, str>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Cow<'a, str>>, // FULL or MINI
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FullTicker24hrResponse {
+    pub symbol: String,
+    pub price_change: Decimal,
+    pub price_change_percent: String,
+    pub weighted_avg_price: Decimal,
+    pub prev_close_price: Decimal,
+    pub last_price: Decimal,
+    pub last_qty: Decimal,
+    pub bid_price: Decimal,
+    pub bid_qty: Decimal,
+    pub ask_price: Decimal,
+    pub ask_qty: Decimal,
+    pub open_price: Decimal,
+    pub high_price: Decimal,
+    pub low_price: Decimal,
+    pub volume: Decimal,
+    pub quote_volume: Decimal,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: i64,
+    pub last_id: i64,
+    pub count: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MiniTickerResponse {
+    pub symbol: String,
+    pub open_price: Decimal,
+    pub high_price: Decimal,
+    pub low_price: Decimal,
+    pub last_price: Decimal,
+    pub volume: Decimal,
+    pub quote_volume: Decimal,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: i64,
+    pub last_id: i64,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum Ticker24hrResponse {
+    Full(FullTicker24hrResponse),
+    Mini(MiniTickerResponse),
+}
+
+// A single ticker when `symbol` is set
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum Tickers24hrResponse {
+    One(Ticker24hrResponse),
+    All(Vec<Ticker24hrResponse>),
+}
+
+impl PublicRequest<Spot> for Ticker24hrRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/ticker/24hr";
+    type Response = Tickers24hrResponse;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RollingWindowTickerRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "json_array")]
+    pub symbols: Option<Vec<Cow<'a, str>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_size: Option<Cow<'a, str>>, // 1m..59m, 1h..23h or 1d..7d, defaults to 1d
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Cow<'a, str>>, // FULL or MINI
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FullRollingWindowTickerResponse {
+    pub symbol: String,
+    pub price_change: Decimal,
+    pub price_change_percent: String,
+    pub weighted_avg_price: Decimal,
+    pub open_price: Decimal,
+    pub high_price: Decimal,
+    pub low_price: Decimal,
+    pub last_price: Decimal,
+    pub volume: Decimal,
+    pub quote_volume: Decimal,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: i64,
+    pub last_id: i64,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum RollingWindowTickerResponse {
+    Full(FullRollingWindowTickerResponse),
+    Mini(MiniTickerResponse),
+}
+
+// A single ticker when `symbol` is set
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum RollingWindowTickersResponse {
+    One(RollingWindowTickerResponse),
+    All(Vec<RollingWindowTickerResponse>),
+}
+
+impl PublicRequest<Spot> for RollingWindowTickerRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/ticker";
+    type Response = RollingWindowTickersResponse;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PriceTickerRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "json_array")]
+    pub symbols: Option<Vec<Cow<'a, str>>>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PriceTickerResponse {
+    pub symbol: String,
+    pub price: Decimal,
+}
+
+// A single ticker when `symbol` is set
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum PriceTickersResponse {
+    One(PriceTickerResponse),
+    All(Vec<PriceTickerResponse>),
+}
+
+impl PublicRequest<Spot> for PriceTickerRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/ticker/price";
+    type Response = PriceTickersResponse;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BookTickerRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "json_array")]
+    pub symbols: Option<Vec<Cow<'a, str>>>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BookTickerResponse {
+    pub symbol: String,
+    pub bid_price: Decimal,
+    pub bid_qty: Decimal,
+    pub ask_price: Decimal,
+    pub ask_qty: Decimal,
+}
+
+// A single ticker when `symbol` is set
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BookTickersResponse {
+    One(BookTickerResponse),
+    All(Vec<BookTickerResponse>),
+}
+
+impl PublicRequest<Spot> for BookTickerRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/ticker/bookTicker";
+    type Response = BookTickersResponse;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    pub side: Side,
+    pub r#type: OrderType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_order_qty: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy_type: Option<u64>, // >= 1_000_000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_delta: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iceberg_qty: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<Cow<'a, str>>, // ACK, RESULT or FULL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+// Builds a `NewOrderRequest` from the fields its order type requires
+#[derive(Debug, Clone)]
+pub struct NewOrder<'a>(NewOrderRequest<'a>);
+
+/// GTC or post only limit order, the ones that can be icebergs
+///
+/// ```
+/// use binance_async_api::{decimal::decimal_param, enums::{Side, TimeInForce}, rest::spot::NewOrder};
+/// let (qty, price, iceberg) = (decimal_param("1").unwrap(), decimal_param("100").unwrap(), decimal_param("0.1").unwrap());
+/// NewOrder::limit("BTCUSDT", Side::Buy, qty, price, TimeInForce::Ioc);
+/// ```
+///
+/// ```compile_fail
+/// use binance_async_api::{decimal::decimal_param, enums::{Side, TimeInForce}, rest::spot::NewOrder};
+/// let (qty, price, iceberg) = (decimal_param("1").unwrap(), decimal_param("100").unwrap(), decimal_param("0.1").unwrap());
+/// NewOrder::limit("BTCUSDT", Side::Buy, qty, price, TimeInForce::Ioc).iceberg_qty(iceberg);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LimitOrder<'a>(NewOrderRequest<'a>);
+
+// Trailing the price, from an activation price if one is given
+#[derive(Debug, Clone)]
+pub struct TrailingStopOrder<'a>(NewOrderRequest<'a>);
+
+impl<'a> NewOrder<'a> {
+    fn new(symbol: impl Into<Cow<'a, str>>, side: Side, r#type: OrderType) -> Self {
+        NewOrder(NewOrderRequest {
+            symbol: symbol.into(),
+            side,
+            r#type,
+            time_in_force: None,
+            quantity: None,
+            quote_order_qty: None,
+            price: None,
+            new_client_order_id: None,
+            strategy_id: None,
+            strategy_type: None,
+            stop_price: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+            recv_window: None,
+            timestamp: 0,
+        })
+    }
+
+    pub fn limit(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+        price: impl Into<DecimalParam<'a>>,
+        time_in_force: TimeInForce,
+    ) -> Self {
+        let mut order = Self::new(symbol, side, OrderType::Limit);
+        order.0.quantity = Some(quantity.into());
+        order.0.price = Some(price.into());
+        order.0.time_in_force = Some(time_in_force);
+        order
+    }
+
+    pub fn limit_gtc(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+        price: impl Into<DecimalParam<'a>>,
+    ) -> LimitOrder<'a> {
+        LimitOrder(Self::limit(symbol, side, quantity, price, TimeInForce::Gtc).0)
+    }
+
+    // LIMIT_MAKER, rejected instead of taking liquidity
+    pub fn post_only(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+        price: impl Into<DecimalParam<'a>>,
+    ) -> LimitOrder<'a> {
+        let mut order = Self::new(symbol, side, OrderType::LimitMaker);
+        order.0.quantity = Some(quantity.into());
+        order.0.price = Some(price.into());
+        LimitOrder(order.0)
+    }
+
+    pub fn market(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+    ) -> Self {
+        let mut order = Self::new(symbol, side, OrderType::Market);
+        order.0.quantity = Some(quantity.into());
+        order
+    }
+
+    // MARKET order spending or receiving `quote_order_qty` of the quote asset
+    pub fn market_quote(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quote_order_qty: impl Into<DecimalParam<'a>>,
+    ) -> Self {
+        let mut order = Self::new(symbol, side, OrderType::Market);
+        order.0.quote_order_qty = Some(quote_order_qty.into());
+        order
+    }
+
+    // STOP_LOSS, a market order placed once `stop_price` is reached
+    pub fn stop_market(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+        stop_price: impl Into<DecimalParam<'a>>,
+    ) -> Self {
+        let mut order = Self::new(symbol, side, OrderType::StopLoss);
+        order.0.quantity = Some(quantity.into());
+        order.0.stop_price = Some(stop_price.into());
+        order
+    }
+
+    pub fn stop_loss_limit(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+        price: impl Into<DecimalParam<'a>>,
+        stop_price: impl Into<DecimalParam<'a>>,
+        time_in_force: TimeInForce,
+    ) -> Self {
+        let mut order = Self::limit(symbol, side, quantity, price, time_in_force);
+        order.0.r#type = OrderType::StopLossLimit;
+        order.0.stop_price = Some(stop_price.into());
+        order
+    }
+
+    // TAKE_PROFIT, a market order placed once `stop_price` is reached
+    pub fn take_profit(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+        stop_price: impl Into<DecimalParam<'a>>,
+    ) -> Self {
+        let mut order = Self::new(symbol, side, OrderType::TakeProfit);
+        order.0.quantity = Some(quantity.into());
+        order.0.stop_price = Some(stop_price.into());
+        order
+    }
+
+    pub fn take_profit_limit(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+        price: impl Into<DecimalParam<'a>>,
+        stop_price: impl Into<DecimalParam<'a>>,
+        time_in_force: TimeInForce,
+    ) -> Self {
+        let mut order = Self::limit(symbol, side, quantity, price, time_in_force);
+        order.0.r#type = OrderType::TakeProfitLimit;
+        order.0.stop_price = Some(stop_price.into());
+        order
+    }
+
+    // STOP_LOSS trailing the price by `trailing_delta` BIPS, right away
+    // unless given an `activation_price`
+    pub fn trailing_stop(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+        trailing_delta: u64,
+    ) -> TrailingStopOrder<'a> {
+        let mut order = Self::new(symbol, side, OrderType::StopLoss);
+        order.0.quantity = Some(quantity.into());
+        order.0.trailing_delta = Some(trailing_delta);
+        TrailingStopOrder(order.0)
+    }
+}
+
+impl<'a> TrailingStopOrder<'a> {
+    pub fn activation_price(mut self, activation_price: impl Into<DecimalParam<'a>>) -> Self {
+        self.0.stop_price = Some(activation_price.into());
+        self
+    }
+}
+
+impl<'a> LimitOrder<'a> {
+    pub fn iceberg_qty(mut self, iceberg_qty: impl Into<DecimalParam<'a>>) -> Self {
+        self.0.iceberg_qty = Some(iceberg_qty.into());
+        self
+    }
+}
+
+// Options that every order type takes
+macro_rules! order_options {
+    ($($order:ident),+) => {$(
+        impl<'a> $order<'a> {
+            pub fn new_client_order_id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+                self.0.new_client_order_id = Some(id.into());
+                self
+            }
+
+            pub fn strategy(mut self, strategy_id: u64, strategy_type: u64) -> Self {
+                self.0.strategy_id = Some(strategy_id);
+                self.0.strategy_type = Some(strategy_type);
+                self
+            }
+
+            pub fn new_order_resp_type(mut self, resp_type: impl Into<Cow<'a, str>>) -> Self {
+                self.0.new_order_resp_type = Some(resp_type.into());
+                self
+            }
+
+            pub fn self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+                self.0.self_trade_prevention_mode = Some(mode);
+                self
+            }
+
+            pub fn recv_window(mut self, recv_window: u64) -> Self {
+                self.0.recv_window = Some(recv_window);
+                self
+            }
+
+            pub fn build(mut self, timestamp: u64) -> NewOrderRequest<'a> {
+                self.0.timestamp = timestamp;
+                self.0
+            }
+        }
+    )+};
+}
+
+order_options!(NewOrder, LimitOrder, TrailingStopOrder);
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NewOrderAckResponse {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: i64, // -1 if the order is not part of a list
+    pub client_order_id: String,
+    pub transact_time: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NewOrderResultResponse {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: i64, // -1 if the order is not part of a list
+    pub client_order_id: String,
+    pub transact_time: u64,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub orig_quote_order_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub side: Side,
+    pub stop_price: Option<Decimal>,
+    pub iceberg_qty: Option<Decimal>,
+    pub working_time: u64,
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+    pub prevented_match_id: Option<u64>,
+    pub prevented_quantity: Option<Decimal>,
+    pub strategy_id: Option<u64>,
+    pub strategy_type: Option<u64>,
+    pub trailing_delta: Option<u64>,
+    pub trailing_time: Option<i64>,
+    pub working_floor: Option<String>,
+    pub used_sor: Option<bool>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NewOrderFullResponse {
+    #[serde(flatten)]
+    pub result: NewOrderResultResponse,
+    pub fills: Vec<Fill>,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Fill {
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub commission: Decimal,
+    pub commission_asset: String,
+    pub trade_id: u64,
+    pub match_type: Option<String>,
+    pub alloc_id: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum NewOrderResponse {
+    Full(NewOrderFullResponse),
+    Result(NewOrderResultResponse),
+    Ack(NewOrderAckResponse),
+}
+
+impl SignedRequest<Spot> for NewOrderRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/api/v3/order";
+    const ORDER_ENTRY: bool = true;
+    type Response = NewOrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestNewOrderRequest<'a> {
+    #[serde(flatten)]
+    pub order: NewOrderRequest<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compute_commission_rates: Option<bool>,
+}
+
+// Empty unless `compute_commission_rates` was set on the request
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TestNewOrderResponse {
+    pub standard_commission_for_order: Option<OrderCommissionRates>,
+    pub special_commission_for_order: Option<OrderCommissionRates>,
+    pub tax_commission_for_order: Option<OrderCommissionRates>,
+    pub discount: Option<CommissionDiscount>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrderCommissionRates {
+    pub maker: String,
+    pub taker: String,
+}
+
+impl SignedRequest<Spot> for TestNewOrderRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/api/v3/order/test";
+    const DRY_RUN: bool = true;
+    type Response = TestNewOrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.order.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.order.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct QueryOrderResponse {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: i64, // -1 if the order is not part of a list
+    pub client_order_id: String,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub side: Side,
+    pub stop_price: Decimal,
+    pub iceberg_qty: Decimal,
+    pub time: u64,
+    pub update_time: u64,
+    pub is_working: bool,
+    pub working_time: i64,
+    pub orig_quote_order_qty: Decimal,
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+    pub prevented_match_id: Option<u64>,
+    pub prevented_quantity: Option<Decimal>,
+    pub strategy_id: Option<u64>,
+    pub strategy_type: Option<u64>,
+    pub trailing_delta: Option<u64>,
+    pub trailing_time: Option<i64>,
+    pub working_floor: Option<String>,
+    pub used_sor: Option<bool>,
+}
+
+impl SignedRequest<Spot> for QueryOrderRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/order";
+    type Response = QueryOrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_restrictions: Option<Cow<'a, str>>, // ONLY_NEW or ONLY_PARTIALLY_FILLED
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CancelOrderResponse {
+    pub symbol: String,
+    pub orig_client_order_id: String,
+    pub order_id: u64,
+    pub order_list_id: i64, // -1 if the order is not part of a list
+    pub client_order_id: String,
+    pub transact_time: u64,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub orig_quote_order_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub side: Side,
+    pub stop_price: Option<Decimal>,
+    pub iceberg_qty: Option<Decimal>,
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+    pub prevented_match_id: Option<u64>,
+    pub prevented_quantity: Option<Decimal>,
+    pub strategy_id: Option<u64>,
+    pub strategy_type: Option<u64>,
+    pub trailing_delta: Option<u64>,
+    pub trailing_time: Option<i64>,
+}
+
+impl SignedRequest<Spot> for CancelOrderRequest<'_> {
+    const METHOD: Method = Method::DELETE;
+    const ENDPOINT: &'static str = "/api/v3/order";
+    const ORDER_ENTRY: bool = true;
+    type Response = CancelOrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOrdersRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<Spot> for OpenOrdersRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/openOrders";
+    type Response = Vec<QueryOrderResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOpenOrdersRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum CancelOpenOrderResponse {
+    OrderList(CancelOrderListResponse),
+    Order(CancelOrderResponse),
+}
+
+impl SignedRequest<Spot> for CancelOpenOrdersRequest<'_> {
+    const METHOD: Method = Method::DELETE;
+    const ENDPOINT: &'static str = "/api/v3/openOrders";
+    const ORDER_ENTRY: bool = true;
+    type Response = Vec<CancelOpenOrderResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOcoOrderListRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list_client_order_id: Option<Cow<'a, str>>,
+    pub side: Side,
+    pub quantity: DecimalParam<'a>,
+    pub above_type: OrderType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub above_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub above_iceberg_qty: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub above_price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub above_stop_price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub above_trailing_delta: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub above_time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub above_strategy_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub above_strategy_type: Option<u64>, // >= 1_000_000
+    pub below_type: OrderType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub below_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub below_iceberg_qty: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub below_price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub below_stop_price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub below_trailing_delta: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub below_time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub below_strategy_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub below_strategy_type: Option<u64>, // >= 1_000_000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<Cow<'a, str>>, // ACK, RESULT or FULL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrderListOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NewOrderListResponse {
+    pub order_list_id: u64,
+    pub contingency_type: String,
+    pub list_status_type: String,
+    pub list_order_status: String,
+    pub list_client_order_id: String,
+    pub transaction_time: u64,
+    pub symbol: String,
+    pub orders: Vec<OrderListOrder>,
+    pub order_reports: Vec<NewOrderResponse>,
+}
+
+impl SignedRequest<Spot> for NewOcoOrderListRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/api/v3/orderList/oco";
+    const ORDER_ENTRY: bool = true;
+    type Response = NewOrderListResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryOrderListRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_list_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrderListResponse {
+    pub order_list_id: u64,
+    pub contingency_type: String,
+    pub list_status_type: String,
+    pub list_order_status: String,
+    pub list_client_order_id: String,
+    pub transaction_time: u64,
+    pub symbol: String,
+    pub orders: Vec<OrderListOrder>,
+}
+
+impl SignedRequest<Spot> for QueryOrderListRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/orderList";
+    type Response = OrderListResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderListRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_list_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CancelOrderListResponse {
+    pub order_list_id: u64,
+    pub contingency_type: String,
+    pub list_status_type: String,
+    pub list_order_status: String,
+    pub list_client_order_id: String,
+    pub transaction_time: u64,
+    pub symbol: String,
+    pub orders: Vec<OrderListOrder>,
+    pub order_reports: Vec<CancelOrderResponse>,
+}
+
+impl SignedRequest<Spot> for CancelOrderListRequest<'_> {
+    const METHOD: Method = Method::DELETE;
+    const ENDPOINT: &'static str = "/api/v3/orderList";
+    const ORDER_ENTRY: bool = true;
+    type Response = CancelOrderListResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOrderListsRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<Spot> for OpenOrderListsRequest {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/openOrderList";
+    type Response = Vec<OrderListResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewSorOrderRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    pub side: Side,
+    pub r#type: OrderType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    pub quantity: DecimalParam<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy_type: Option<u64>, // >= 1_000_000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iceberg_qty: Option<DecimalParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<Cow<'a, str>>, // ACK, RESULT or FULL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+impl SignedRequest<Spot> for NewSorOrderRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/api/v3/sor/order";
+    const ORDER_ENTRY: bool = true;
+    type Response = NewOrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestNewSorOrderRequest<'a> {
+    #[serde(flatten)]
+    pub order: NewSorOrderRequest<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compute_commission_rates: Option<bool>,
+}
+
+impl SignedRequest<Spot> for TestNewSorOrderRequest<'_> {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/api/v3/sor/order/test";
+    const DRY_RUN: bool = true;
+    type Response = TestNewOrderResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.order.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.order.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformationRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub omit_zero_balances: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AccountInformationResponse {
+    pub maker_commission: u64,
+    pub taker_commission: u64,
+    pub buyer_commission: u64,
+    pub seller_commission: u64,
+    pub commission_rates: CommissionRates,
+    pub can_trade: bool,
+    pub can_withdraw: bool,
+    pub can_deposit: bool,
+    pub brokered: bool,
+    pub require_self_trade_prevention: bool,
+    pub prevent_sor: bool,
+    pub update_time: u64,
+    pub account_type: String,
+    pub balances: Vec<Balance>,
+    pub permissions: Vec<String>,
+    pub uid: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommissionRates {
+    pub maker: String,
+    pub taker: String,
+    pub buyer: String,
+    pub seller: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Balance {
+    pub asset: String,
+    pub free: Decimal,
+    pub locked: Decimal,
+}
+
+impl SignedRequest<Spot> for AccountInformationRequest {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/account";
+    type Response = AccountInformationResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountTradesRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 1000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AccountTradeResponse {
+    pub symbol: String,
+    pub id: u64,
+    pub order_id: u64,
+    pub order_list_id: i64, // -1 if the order is not part of a list
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub quote_qty: Decimal,
+    pub commission: Decimal,
+    pub commission_asset: String,
+    pub time: u64,
+    pub is_buyer: bool,
+    pub is_maker: bool,
+    pub is_best_match: bool,
+}
+
+impl SignedRequest<Spot> for AccountTradesRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/myTrades";
+    type Response = Vec<AccountTradeResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnfilledOrderCountRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UnfilledOrderCountResponse {
+    pub rate_limit_type: RateLimitType,
+    pub interval: RateLimitInterval,
+    pub interval_num: u64,
+    pub limit: u64,
+    pub count: u64,
+}
+
+impl SignedRequest<Spot> for UnfilledOrderCountRequest {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/rateLimit/order";
+    type Response = Vec<UnfilledOrderCountResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreventedMatchesRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prevented_match_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_prevented_match_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 1000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PreventedMatchResponse {
+    pub symbol: String,
+    pub prevented_match_id: u64,
+    pub taker_order_id: u64,
+    pub maker_symbol: String,
+    pub maker_order_id: u64,
+    pub trade_group_id: u64,
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+    pub price: Decimal,
+    pub maker_prevented_quantity: Decimal,
+    pub transact_time: u64,
+}
+
+impl SignedRequest<Spot> for PreventedMatchesRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/myPreventedMatches";
+    type Response = Vec<PreventedMatchResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AllocationsRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_allocation_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>, // <= 1000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AllocationResponse {
+    pub symbol: String,
+    pub allocation_id: u64,
+    pub allocation_type: String,
+    pub order_id: u64,
+    pub order_list_id: i64, // -1 if the order is not part of a list
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub quote_qty: Decimal,
+    pub commission: Decimal,
+    pub commission_asset: String,
+    pub time: u64,
+    pub is_buyer: bool,
+    pub is_maker: bool,
+    pub is_allocator: bool,
+}
+
+impl SignedRequest<Spot> for AllocationsRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/myAllocations";
+    type Response = Vec<AllocationResponse>;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionRatesRequest<'a> {
+    pub symbol: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>, // <= 60_000
+    pub timestamp: u64,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommissionRatesResponse {
+    pub symbol: String,
+    pub standard_commission: CommissionRates,
+    pub special_commission: Option<CommissionRates>,
+    pub tax_commission: CommissionRates,
+    pub discount: CommissionDiscount,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommissionDiscount {
+    pub enabled_for_account: bool,
+    pub enabled_for_symbol: bool,
+    pub discount_asset: String,
+    pub discount: Decimal,
+}
+
+impl SignedRequest<Spot> for CommissionRatesRequest<'_> {
+    const METHOD: Method = Method::GET;
+    const ENDPOINT: &'static str = "/api/v3/account/commission";
+    type Response = CommissionRatesResponse;
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct CreateListenKeyRequest {}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CreateListenKeyResponse {
+    pub listen_key: String,
+}
+
+impl KeyedRequest<Spot> for CreateListenKeyRequest {
+    const METHOD: Method = Method::POST;
+    const ENDPOINT: &'static str = "/api/v3/userDataStream";
+    type Response = CreateListenKeyResponse;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeepAliveListenKeyRequest<'a> {
+    pub listen_key: Cow<'a, str>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct KeepAliveListenKeyResponse {}
+
+impl KeyedRequest<Spot> for KeepAliveListenKeyRequest<'_> {
+    const METHOD: Method = Method::PUT;
+    const ENDPOINT: &'static str = "/api/v3/userDataStream";
+    type Response = KeepAliveListenKeyResponse;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloseListenKeyRequest<'a> {
+    pub listen_key: Cow<'a, str>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CloseListenKeyResponse {}
+
+impl KeyedRequest<Spot> for CloseListenKeyRequest<'_> {
+    const METHOD: Method = Method::DELETE;
+    const ENDPOINT: &'static str = "/api/v3/userDataStream";
+    type Response = CloseListenKeyResponse;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{BinanceClient, ClientConfig, Environment},
+        errors::RequestError,
+    };
+
+    #[tokio::test]
+    async fn test_exchange_info_request() {
+        let client = BinanceClient::spot();
+        let req = ExchangeInfoRequest {
+            symbol: None,
+            symbols: None,
+            permissions: None,
+            show_permission_sets: None,
+            symbol_status: None,
+        };
+        let res = client.request(&req).await.unwrap();
+        assert!(res.status.is_success());
+    }
+
+    #[tokio::test]
+    async fn test_exchange_info_symbols_request() {
+        let client = BinanceClient::spot();
+        let req = ExchangeInfoRequest {
+            symbol: None,
+            symbols: Some(vec!["BTCUSDT".into(), "ETHUSDT".into()]),
+            permissions: None,
+            show_permission_sets: None,
+            symbol_status: None,
+        };
+        let res = client.request(&req).await.unwrap();
+        assert_eq!(res.content.symbols.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_order_book_request() {
+        let client = BinanceClient::spot();
+        let req = OrderBookRequest {
+            symbol: "BTCUSDT".into(),
+            limit: Some(5),
+        };
+        let res = client.request(&req).await.unwrap();
+        assert!(res.status.is_success());
+    }
+
+    #[tokio::test]
+    async fn test_klines_request() {
+        let client = BinanceClient::spot();
+        let req = KlinesRequest {
+            symbol: "BTCUSDT".into(),
+            interval: "1m".into(),
+            start_time: None,
+            end_time: None,
+            time_zone: None,
+            limit: Some(5),
+        };
+        let res = client.request(&req).await.unwrap();
+        assert!(res.status.is_success());
+    }
+
+    #[tokio::test]
+    async fn test_paper_client_refuses_production_orders() {
+        let config = ClientConfig::environment(Environment::Testnet)
+            .with_rest_base_url("https://api.binance.com".to_string());
+        let client = BinanceClient::spot().with_config(config);
+        let req = CancelOrderRequest {
+            symbol: "BTCUSDT".into(),
+            order_id: Some(1),
+            orig_client_order_id: None,
+            new_client_order_id: None,
+            cancel_restrictions: None,
+            recv_window: None,
+            timestamp: 0,
+        };
+        let res = client.signed_request(&req, "key", "secret").await;
+        assert!(matches!(res, Err(RequestError::PaperMode)));
+
+        // Any production host, not only the preset
+        let config = ClientConfig::environment(Environment::Testnet)
+            .with_rest_base_url("https://api1.binance.com/".to_string());
+        let client = BinanceClient::spot().with_config(config);
+        let res = client.signed_request(&req, "key", "secret").await;
+        assert!(matches!(res, Err(RequestError::PaperMode)));
+
+        // Test orders don't reach the matching engine
+        let req = TestNewOrderRequest {
+            order: NewOrder::market(
+                "BTCUSDT",
+                Side::Buy,
+                crate::validation::tests::param("0.001").unwrap(),
+            )
+            .build(0),
+            compute_commission_rates: None,
+        };
+        let res = client.signed_request(&req, "key", "secret").await;
+        assert!(!matches!(res, Err(RequestError::PaperMode)));
+    }
+
+    #[test]
+    fn test_new_order_builder() {
+        use crate::validation::tests::param;
+        let order =
+            NewOrder::trailing_stop("BTCUSDT", Side::Sell, param("0.01").unwrap(), 100).build(1);
+        assert_eq!(
+            serde_qs::to_string(&order).unwrap(),
+            "symbol=BTCUSDT&side=SELL&type=STOP_LOSS&quantity=0.01&trailingDelta=100&timestamp=1"
+        );
+        let order = NewOrder::trailing_stop("BTCUSDT", Side::Sell, param("0.01").unwrap(), 100)
+            .activation_price(param("65000").unwrap())
+            .build(1);
+        assert_eq!(
+            serde_qs::to_string(&order).unwrap(),
+            "symbol=BTCUSDT&side=SELL&type=STOP_LOSS&quantity=0.01&stopPrice=65000&trailingDelta=100&timestamp=1"
+        );
+        let order = NewOrder::post_only(
+            "BTCUSDT",
+            Side::Buy,
+            param("0.01").unwrap(),
+            param("60000.1").unwrap(),
+        )
+        .iceberg_qty(param("0.001").unwrap())
+        .build(1);
+        assert_eq!(
+            serde_qs::to_string(&order).unwrap(),
+            "symbol=BTCUSDT&side=BUY&type=LIMIT_MAKER&quantity=0.01&price=60000.1&icebergQty=0.001&timestamp=1"
+        );
+        let order = NewOrder::limit_gtc(
+            "BTCUSDT",
+            Side::Sell,
+            param("0.01").unwrap(),
+            param("60000.1").unwrap(),
+        )
+        .iceberg_qty(param("0.001").unwrap())
+        .new_client_order_id("iceberg")
+        .build(1);
+        assert_eq!(
+            serde_qs::to_string(&order).unwrap(),
+            "symbol=BTCUSDT&side=SELL&type=LIMIT&timeInForce=GTC&quantity=0.01&price=60000.1&newClientOrderId=iceberg&icebergQty=0.001&timestamp=1"
+        );
+    }
+
+    #[test]
+    fn test_response_round_trip() {
+        use crate::response::tests::assert_round_trip;
+        assert_round_trip::<OrderBookResponse>(
+            r#"{"lastUpdateId":1027024,"bids":[["4","431"]],"asks":[["4.000002","12"]]}"#,
+        );
+        assert_round_trip::<Vec<KlineResponse>>(
+            r#"[[1499040000000,"0.0163479","0.8","0.015758","0.015771","148976.11427815",1499644799999,"2434.19055334",308,"1756.87402397","28.46694368","0"]]"#,
+        );
+        assert_round_trip::<AvgPriceResponse>(
+            r#"{"mins":5,"price":"9.35751834","closeTime":1694061154503}"#,
+        );
+        assert_round_trip::<NewOrderResponse>(
+            r#"{"symbol":"BTCUSDT","orderId":28,"orderListId":-1,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP","transactTime":1507725176595,"price":"0","origQty":"10","executedQty":"10","origQuoteOrderQty":"0","cummulativeQuoteQty":"10","status":"FILLED","timeInForce":"GTC","type":"MARKET","side":"SELL","workingTime":1507725176595,"selfTradePreventionMode":"NONE","fills":[{"price":"4000","qty":"1","commission":"4","commissionAsset":"USDT","tradeId":56}]}"#,
+        );
+        assert_round_trip::<QueryOrderResponse>(
+            r#"{"symbol":"LTCBTC","orderId":1,"orderListId":-1,"clientOrderId":"myOrder1","price":"0.1","origQty":"1","executedQty":"0","cummulativeQuoteQty":"0","status":"NEW","timeInForce":"GTC","type":"LIMIT","side":"BUY","stopPrice":"0","icebergQty":"0","time":1499827319559,"updateTime":1499827319559,"isWorking":true,"workingTime":1499827319559,"origQuoteOrderQty":"0","selfTradePreventionMode":"NONE"}"#,
+        );
+        assert_round_trip::<AccountInformationResponse>(
+            r#"{"makerCommission":15,"takerCommission":15,"buyerCommission":0,"sellerCommission":0,"commissionRates":{"maker":"0.00150000","taker":"0.00150000","buyer":"0.00000000","seller":"0.00000000"},"canTrade":true,"canWithdraw":true,"canDeposit":true,"brokered":false,"requireSelfTradePrevention":false,"preventSor":false,"updateTime":123456789,"accountType":"SPOT","balances":[{"asset":"BTC","free":"4723846.89208129","locked":"0"}],"permissions":["SPOT"],"uid":354937868}"#,
+        );
+    }
+
+    #[test]
+    fn test_tickers_response() {
+        use crate::response::tests::assert_round_trip;
+        let one = r#"{"symbol":"LTCBTC","price":"4.00000200"}"#;
+        let all =
+            r#"[{"symbol":"LTCBTC","price":"4.000002"},{"symbol":"ETHBTC","price":"0.07946600"}]"#;
+        assert!(matches!(
+            serde_json::from_str(one).unwrap(),
+            PriceTickersResponse::One(_)
+        ));
+        assert!(matches!(
+            serde_json::from_str(all).unwrap(),
+            PriceTickersResponse::All(tickers) if tickers.len() == 2
+        ));
+        assert_round_trip::<BookTickersResponse>(
+            r#"[{"symbol":"LTCBTC","bidPrice":"4.000002","bidQty":"431","askPrice":"4.000003","askQty":"9"}]"#,
+        );
+    }
+}
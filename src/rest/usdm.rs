@@ -1,14 +1,25 @@
-use crate::client::Usdm;
+use crate::{
+    client::Usdm,
+    decimal::{Decimal, DecimalParam},
+    enums::{
+        OrderStatus, OrderType, PositionSide, SelfTradePreventionMode, Side, TimeInForce,
+        WorkingType,
+    },
+};
 
 use super::{KeyedRequest, PublicRequest, SignedRequest};
+use binance_async_api_macros::wire_names;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+pub use super::filters::{ExchangeFilter, RateLimit, SymbolFilter};
 
 #[derive(Debug, Clone, Copy, Serialize)]
 pub struct ExchangeInfoRequest;
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ExchangeInfoResponse {
     pub exchange_filters: Vec<ExchangeFilter>,
     pub rate_limits: Vec<RateLimit>,
@@ -18,30 +29,16 @@ pub struct ExchangeInfoResponse {
     pub timezone: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-pub struct ExchangeFilter {
-    // No info about this on binance api docs
-}
-
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct RateLimit {
-    pub rate_limit_type: String,
-    pub interval: String,
-    pub interval_num: u64,
-    pub limit: u64,
-}
-
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Asset {
     pub asset: String,
     pub margin_available: bool,
     pub auto_asset_exchange: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Market {
     pub symbol: String,
     pub pair: String,
@@ -62,70 +59,32 @@ pub struct Market {
     pub underlying_sub_type: Vec<String>,
     pub trigger_protect: String,
     pub filters: Vec<SymbolFilter>,
-    pub order_types: Vec<String>,
-    pub time_in_force: Vec<String>,
-    pub liquidation_fee: String,
+    pub order_types: Vec<OrderType>,
+    pub time_in_force: Vec<TimeInForce>,
+    pub liquidation_fee: Decimal,
     pub market_take_bound: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(tag = "filterType", rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum SymbolFilter {
-    #[serde(rename_all = "camelCase")]
-    PriceFilter {
-        min_price: String,
-        max_price: String,
-        tick_size: String,
-    },
-    #[serde(rename_all = "camelCase")]
-    LotSize {
-        min_qty: String,
-        max_qty: String,
-        step_size: String,
-    },
-    #[serde(rename_all = "camelCase")]
-    MarketLotSize {
-        min_qty: String,
-        max_qty: String,
-        step_size: String,
-    },
-    MaxNumOrders {
-        limit: u64,
-    },
-    MaxNumAlgoOrders {
-        limit: u64,
-    },
-    #[serde(rename_all = "camelCase")]
-    PercentPrice {
-        multiplier_up: String,
-        multiplier_down: String,
-        multiplier_decimal: String,
-    },
-    MinNotional {
-        notional: String,
-    },
-}
-
 impl PublicRequest<Usdm> for ExchangeInfoRequest {
     const METHOD: Method = Method::GET;
     const ENDPOINT: &'static str = "/fapi/v1/exchangeInfo";
     type Response = ExchangeInfoResponse;
 }
 
-#[derive(Debug, Clone, Copy, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct OrderBookRequest<'a> {
-    pub symbol: &'a str,
+    pub symbol: Cow<'a, str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct OrderBookResponse {
     pub last_update_id: u64,
-    #[serde(rename = "E")]
+    #[wire = "E"]
     pub message_output_time: u64,
-    #[serde(rename = "T")]
+    #[wire = "T"]
     pub transaction_time: u64,
     pub bids: Vec<BookLevel>,
     pub asks: Vec<BookLevel>,
@@ -133,25 +92,27 @@ pub struct OrderBookResponse {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct BookLevel {
-    pub price: String,
-    pub qty: String,
+    pub price: Decimal,
+    pub qty: Decimal,
 }
 
+serialize_as_tuple!(BookLevel { price, qty });
+
 impl PublicRequest<Usdm> for OrderBookRequest<'_> {
     const METHOD: Method = Method::GET;
     const ENDPOINT: &'static str = "/fapi/v1/depth";
     type Response = OrderBookResponse;
 }
 
-#[derive(Debug, Clone, Copy, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PriceTickerRequest<'a> {
-    pub symbol: &'a str,
+    pub symbol: Cow<'a, str>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PriceTickerResponse {
     pub symbol: String,
-    pub price: String,
+    pub price: Decimal,
     pub time: u64,
 }
 
@@ -161,19 +122,19 @@ impl PublicRequest<Usdm> for PriceTickerRequest<'_> {
     type Response = PriceTickerResponse;
 }
 
-#[derive(Debug, Clone, Copy, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BookTickerRequest<'a> {
-    pub symbol: &'a str,
+    pub symbol: Cow<'a, str>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BookTickerResponse {
     pub symbol: String,
-    pub bid_price: String,
-    pub bid_qty: String,
-    pub ask_price: String,
-    pub ask_qty: String,
+    pub bid_price: Decimal,
+    pub bid_qty: Decimal,
+    pub ask_price: Decimal,
+    pub ask_qty: Decimal,
     pub time: u64,
 }
 
@@ -183,10 +144,10 @@ impl PublicRequest<Usdm> for BookTickerRequest<'_> {
     type Response = BookTickerResponse;
 }
 
-#[derive(Debug, Clone, Copy, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RecentAggTradesRequest<'a> {
-    pub symbol: &'a str,
+    pub symbol: Cow<'a, str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub from_id: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -197,21 +158,22 @@ pub struct RecentAggTradesRequest<'a> {
     pub limit: Option<u64>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct AggTradeResponse {
-    #[serde(rename = "a")]
+    #[wire = "a"]
     pub id: u64,
-    #[serde(rename = "p")]
-    pub price: String,
-    #[serde(rename = "q")]
-    pub qty: String,
-    #[serde(rename = "f")]
+    #[wire = "p"]
+    pub price: Decimal,
+    #[wire = "q"]
+    pub qty: Decimal,
+    #[wire = "f"]
     pub first_trade_id: u64,
-    #[serde(rename = "l")]
+    #[wire = "l"]
     pub last_trade_id: u64,
-    #[serde(rename = "T")]
+    #[wire = "T"]
     pub timestamp: u64,
-    #[serde(rename = "m")]
+    #[wire = "m"]
     pub buyer_is_maker: bool,
 }
 
@@ -224,8 +186,8 @@ impl PublicRequest<Usdm> for RecentAggTradesRequest<'_> {
 #[derive(Debug, Clone, Copy, Serialize)]
 pub struct CreateListenKeyRequest {}
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CreateListenKeyResponse {
     pub listen_key: String,
 }
@@ -239,8 +201,8 @@ impl KeyedRequest<Usdm> for CreateListenKeyRequest {
 #[derive(Debug, Clone, Copy, Serialize)]
 pub struct KeepAliveListenKeyRequest {}
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct KeepAliveListenKeyResponse {
     pub listen_key: String,
 }
@@ -254,7 +216,7 @@ impl KeyedRequest<Usdm> for KeepAliveListenKeyRequest {
 #[derive(Debug, Clone, Copy, Serialize)]
 pub struct CloseListenKeyRequest {}
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CloseListenKeyResponse {}
 
 impl KeyedRequest<Usdm> for CloseListenKeyRequest {
@@ -263,16 +225,16 @@ impl KeyedRequest<Usdm> for CloseListenKeyRequest {
     type Response = CloseListenKeyResponse;
 }
 
-#[derive(Debug, Clone, Copy, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChangePositionModeRequest<'a> {
-    pub dual_side_position: &'a str, // true or false
+    pub dual_side_position: Cow<'a, str>, // true or false
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recv_window: Option<u64>, // <= 60_000
     pub timestamp: u64,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ChangePositionModeResponse {}
 
 impl SignedRequest<Usdm> for ChangePositionModeRequest<'_> {
@@ -288,38 +250,38 @@ impl SignedRequest<Usdm> for ChangePositionModeRequest<'_> {
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NewOrderRequest<'a> {
-    pub symbol: &'a str,
-    pub side: &'a str,
+    pub symbol: Cow<'a, str>,
+    pub side: Side,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub position_side: Option<&'a str>,
-    pub r#type: &'a str,
+    pub position_side: Option<PositionSide>,
+    pub r#type: OrderType,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time_in_force: Option<&'a str>,
+    pub time_in_force: Option<TimeInForce>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub quantity: Option<&'a str>,
+    pub quantity: Option<DecimalParam<'a>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reduce_only: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub price: Option<&'a str>,
+    pub price: Option<DecimalParam<'a>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub new_client_order_id: Option<&'a str>,
+    pub new_client_order_id: Option<Cow<'a, str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_price: Option<&'a str>,
+    pub stop_price: Option<DecimalParam<'a>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub close_position: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub activation_price: Option<&'a str>,
+    pub activation_price: Option<DecimalParam<'a>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub callback_rate: Option<&'a str>,
+    pub callback_rate: Option<Cow<'a, str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub working_type: Option<&'a str>,
+    pub working_type: Option<WorkingType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub price_protect: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub self_trade_prevention_mode: Option<&'a str>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub good_till_date: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -327,39 +289,378 @@ pub struct NewOrderRequest<'a> {
     pub timestamp: u64,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+// Builds a `NewOrderRequest` from the fields its order type requires. Each
+// constructor returns the builder with the options of its order type only.
+#[derive(Debug, Clone)]
+pub struct NewOrder<'a>(NewOrderRequest<'a>);
+
+/// Order placed once a stop price is reached
+///
+/// ```
+/// use binance_async_api::{decimal::decimal_param, enums::{Side, WorkingType}, rest::usdm::NewOrder};
+/// let (qty, price) = (decimal_param("1").unwrap(), decimal_param("100").unwrap());
+/// NewOrder::post_only("BTCUSDT", Side::Buy, qty, price);
+/// ```
+///
+/// ```compile_fail
+/// use binance_async_api::{decimal::decimal_param, enums::{Side, WorkingType}, rest::usdm::NewOrder};
+/// let (qty, price) = (decimal_param("1").unwrap(), decimal_param("100").unwrap());
+/// NewOrder::post_only("BTCUSDT", Side::Buy, qty, price).working_type(WorkingType::MarkPrice);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TriggeredOrder<'a>(NewOrderRequest<'a>);
+
+/// Trailing the price, from an activation price if one is given
+///
+/// ```
+/// use binance_async_api::{decimal::decimal_param, enums::Side, rest::usdm::NewOrder};
+/// let (qty, price) = (decimal_param("1").unwrap(), decimal_param("100").unwrap());
+/// NewOrder::market("BTCUSDT", Side::Sell, qty);
+/// ```
+///
+/// ```compile_fail
+/// use binance_async_api::{decimal::decimal_param, enums::Side, rest::usdm::NewOrder};
+/// let (qty, price) = (decimal_param("1").unwrap(), decimal_param("100").unwrap());
+/// NewOrder::market("BTCUSDT", Side::Sell, qty).activation_price(price);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TrailingStopOrder<'a>(NewOrderRequest<'a>);
+
+/// Closes the whole position, which makes it reduce only already
+///
+/// ```
+/// use binance_async_api::{decimal::decimal_param, enums::Side, rest::usdm::{CloseTrigger, NewOrder}};
+/// let stop = decimal_param("100").unwrap();
+/// NewOrder::close_position("BTCUSDT", Side::Sell, CloseTrigger::StopMarket, stop);
+/// ```
+///
+/// ```compile_fail
+/// use binance_async_api::{decimal::decimal_param, enums::Side, rest::usdm::{CloseTrigger, NewOrder}};
+/// let stop = decimal_param("100").unwrap();
+/// NewOrder::close_position("BTCUSDT", Side::Sell, CloseTrigger::StopMarket, stop).reduce_only();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClosePositionOrder<'a>(NewOrderRequest<'a>);
+
+// Order type of `NewOrder::close_position`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloseTrigger {
+    StopMarket,
+    TakeProfitMarket,
+}
+
+/// Time in force of `NewOrder::limit`, GTX and GTD orders have their own
+/// constructors as a GTD order needs its date
+///
+/// ```
+/// use binance_async_api::{decimal::decimal_param, enums::Side, rest::usdm::{LimitTimeInForce, NewOrder}};
+/// let (qty, price) = (decimal_param("1").unwrap(), decimal_param("100").unwrap());
+/// NewOrder::limit("BTCUSDT", Side::Buy, qty, price, LimitTimeInForce::Gtc);
+/// ```
+///
+/// ```compile_fail
+/// use binance_async_api::{decimal::decimal_param, enums::Side, rest::usdm::{LimitTimeInForce, NewOrder}};
+/// let (qty, price) = (decimal_param("1").unwrap(), decimal_param("100").unwrap());
+/// NewOrder::limit("BTCUSDT", Side::Buy, qty, price, LimitTimeInForce::Gtd);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitTimeInForce {
+    Gtc,
+    Ioc,
+    Fok,
+}
+
+impl<'a> NewOrder<'a> {
+    fn new(symbol: impl Into<Cow<'a, str>>, side: Side, r#type: OrderType) -> Self {
+        NewOrder(NewOrderRequest {
+            symbol: symbol.into(),
+            side,
+            position_side: None,
+            r#type,
+            time_in_force: None,
+            quantity: None,
+            reduce_only: None,
+            price: None,
+            new_client_order_id: None,
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+            self_trade_prevention_mode: None,
+            good_till_date: None,
+            recv_window: None,
+            timestamp: 0,
+        })
+    }
+
+    fn triggered(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        r#type: OrderType,
+        stop_price: impl Into<DecimalParam<'a>>,
+    ) -> TriggeredOrder<'a> {
+        let mut order = Self::new(symbol, side, r#type);
+        order.0.stop_price = Some(stop_price.into());
+        TriggeredOrder(order.0)
+    }
+
+    fn limit_with(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+        price: impl Into<DecimalParam<'a>>,
+        time_in_force: TimeInForce,
+    ) -> Self {
+        let mut order = Self::new(symbol, side, OrderType::Limit);
+        order.0.quantity = Some(quantity.into());
+        order.0.price = Some(price.into());
+        order.0.time_in_force = Some(time_in_force);
+        order
+    }
+
+    pub fn limit(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+        price: impl Into<DecimalParam<'a>>,
+        time_in_force: LimitTimeInForce,
+    ) -> Self {
+        let time_in_force = match time_in_force {
+            LimitTimeInForce::Gtc => TimeInForce::Gtc,
+            LimitTimeInForce::Ioc => TimeInForce::Ioc,
+            LimitTimeInForce::Fok => TimeInForce::Fok,
+        };
+        Self::limit_with(symbol, side, quantity, price, time_in_force)
+    }
+
+    // GTD limit order, expiring at `good_till_date` in milliseconds
+    pub fn gtd(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+        price: impl Into<DecimalParam<'a>>,
+        good_till_date: u64,
+    ) -> Self {
+        let mut order = Self::limit_with(symbol, side, quantity, price, TimeInForce::Gtd);
+        order.0.good_till_date = Some(good_till_date);
+        order
+    }
+
+    // LIMIT order that is canceled instead of taking liquidity
+    pub fn post_only(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+        price: impl Into<DecimalParam<'a>>,
+    ) -> Self {
+        Self::limit_with(symbol, side, quantity, price, TimeInForce::Gtx)
+    }
+
+    pub fn market(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+    ) -> Self {
+        let mut order = Self::new(symbol, side, OrderType::Market);
+        order.0.quantity = Some(quantity.into());
+        order
+    }
+
+    // STOP, a limit order placed once `stop_price` is reached
+    pub fn stop(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+        price: impl Into<DecimalParam<'a>>,
+        stop_price: impl Into<DecimalParam<'a>>,
+    ) -> TriggeredOrder<'a> {
+        let mut order = Self::triggered(symbol, side, OrderType::Stop, stop_price);
+        order.0.quantity = Some(quantity.into());
+        order.0.price = Some(price.into());
+        order
+    }
+
+    pub fn stop_market(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+        stop_price: impl Into<DecimalParam<'a>>,
+    ) -> TriggeredOrder<'a> {
+        let mut order = Self::triggered(symbol, side, OrderType::StopMarket, stop_price);
+        order.0.quantity = Some(quantity.into());
+        order
+    }
+
+    // TAKE_PROFIT, a limit order placed once `stop_price` is reached
+    pub fn take_profit(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+        price: impl Into<DecimalParam<'a>>,
+        stop_price: impl Into<DecimalParam<'a>>,
+    ) -> TriggeredOrder<'a> {
+        let mut order = Self::triggered(symbol, side, OrderType::TakeProfit, stop_price);
+        order.0.quantity = Some(quantity.into());
+        order.0.price = Some(price.into());
+        order
+    }
+
+    pub fn take_profit_market(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+        stop_price: impl Into<DecimalParam<'a>>,
+    ) -> TriggeredOrder<'a> {
+        let mut order = Self::triggered(symbol, side, OrderType::TakeProfitMarket, stop_price);
+        order.0.quantity = Some(quantity.into());
+        order
+    }
+
+    // Activates right away unless given an `activation_price`.
+    // `callback_rate` is a percentage in [0.1, 10].
+    pub fn trailing_stop(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        quantity: impl Into<DecimalParam<'a>>,
+        callback_rate: impl Into<Cow<'a, str>>,
+    ) -> TrailingStopOrder<'a> {
+        let mut order = Self::new(symbol, side, OrderType::TrailingStopMarket);
+        order.0.quantity = Some(quantity.into());
+        order.0.callback_rate = Some(callback_rate.into());
+        TrailingStopOrder(order.0)
+    }
+
+    // Closes the whole position once `stop_price` is reached, no quantity
+    pub fn close_position(
+        symbol: impl Into<Cow<'a, str>>,
+        side: Side,
+        trigger: CloseTrigger,
+        stop_price: impl Into<DecimalParam<'a>>,
+    ) -> ClosePositionOrder<'a> {
+        let r#type = match trigger {
+            CloseTrigger::StopMarket => OrderType::StopMarket,
+            CloseTrigger::TakeProfitMarket => OrderType::TakeProfitMarket,
+        };
+        let mut order = Self::triggered(symbol, side, r#type, stop_price);
+        order.0.close_position = Some(true);
+        ClosePositionOrder(order.0)
+    }
+}
+
+impl<'a> TrailingStopOrder<'a> {
+    pub fn activation_price(mut self, activation_price: impl Into<DecimalParam<'a>>) -> Self {
+        self.0.activation_price = Some(activation_price.into());
+        self
+    }
+}
+
+// Options that every order type takes
+macro_rules! order_options {
+    ($($order:ident),+) => {$(
+        impl<'a> $order<'a> {
+            // Required in hedge mode
+            pub fn position_side(mut self, position_side: PositionSide) -> Self {
+                self.0.position_side = Some(position_side);
+                self
+            }
+
+            pub fn new_client_order_id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+                self.0.new_client_order_id = Some(id.into());
+                self
+            }
+
+            pub fn self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+                self.0.self_trade_prevention_mode = Some(mode);
+                self
+            }
+
+            pub fn recv_window(mut self, recv_window: u64) -> Self {
+                self.0.recv_window = Some(recv_window);
+                self
+            }
+
+            pub fn build(mut self, timestamp: u64) -> NewOrderRequest<'a> {
+                self.0.timestamp = timestamp;
+                self.0
+            }
+        }
+    )+};
+}
+
+// Not on `close_position` orders
+macro_rules! reduce_only {
+    ($($order:ident),+) => {$(
+        impl<'a> $order<'a> {
+            pub fn reduce_only(mut self) -> Self {
+                self.0.reduce_only = Some(true);
+                self
+            }
+        }
+    )+};
+}
+
+// Triggered orders only
+macro_rules! trigger_options {
+    ($($order:ident),+) => {$(
+        impl<'a> $order<'a> {
+            pub fn working_type(mut self, working_type: WorkingType) -> Self {
+                self.0.working_type = Some(working_type);
+                self
+            }
+
+            pub fn price_protect(mut self) -> Self {
+                self.0.price_protect = Some(true);
+                self
+            }
+        }
+    )+};
+}
+
+order_options!(
+    NewOrder,
+    TriggeredOrder,
+    TrailingStopOrder,
+    ClosePositionOrder
+);
+reduce_only!(NewOrder, TriggeredOrder, TrailingStopOrder);
+trigger_options!(TriggeredOrder, TrailingStopOrder, ClosePositionOrder);
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct NewOrderResponse {
     pub client_order_id: String,
-    pub cum_qty: String,
-    pub cum_quote: String,
-    pub executed_qty: String,
+    pub cum_qty: Decimal,
+    pub cum_quote: Decimal,
+    pub executed_qty: Decimal,
     pub order_id: u64,
-    pub avg_price: String,
-    pub orig_qty: String,
-    pub price: String,
+    pub avg_price: Decimal,
+    pub orig_qty: Decimal,
+    pub price: Decimal,
     pub reduce_only: bool,
-    pub side: String,
-    pub position_side: String,
-    pub status: String,
-    pub stop_price: String,
+    pub side: Side,
+    pub position_side: PositionSide,
+    pub status: OrderStatus,
+    pub stop_price: Decimal,
     pub close_position: bool,
     pub symbol: String,
-    pub time_in_force: String,
-    pub r#type: String,
-    pub orig_type: String,
-    pub activate_price: Option<String>,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub orig_type: OrderType,
+    pub activate_price: Option<Decimal>,
     pub price_rate: Option<String>,
     pub update_time: u64,
-    pub working_type: String,
+    pub working_type: WorkingType,
     pub price_protect: bool,
-    pub self_trade_prevention_mode: String,
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
     pub good_till_date: u64,
 }
 
 impl SignedRequest<Usdm> for NewOrderRequest<'_> {
     const METHOD: Method = Method::POST;
     const ENDPOINT: &'static str = "/fapi/v1/order";
+    const ORDER_ENTRY: bool = true;
     type Response = NewOrderResponse;
 
     fn timestamp(&self) -> u64 {
@@ -370,51 +671,52 @@ impl SignedRequest<Usdm> for NewOrderRequest<'_> {
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelOrderRequest<'a> {
-    pub symbol: &'a str,
+    pub symbol: Cow<'a, str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order_id: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub orig_client_order_id: Option<&'a str>,
+    pub orig_client_order_id: Option<Cow<'a, str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recv_window: Option<u64>, // <= 60_000
     pub timestamp: u64,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CancelOrderResponse {
     pub client_order_id: String,
-    pub cum_qty: String,
-    pub cum_quote: String,
-    pub executed_qty: String,
+    pub cum_qty: Decimal,
+    pub cum_quote: Decimal,
+    pub executed_qty: Decimal,
     pub order_id: u64,
-    pub orig_qty: String,
-    pub orig_type: String,
-    pub price: String,
+    pub orig_qty: Decimal,
+    pub orig_type: OrderType,
+    pub price: Decimal,
     pub reduce_only: bool,
-    pub side: String,
-    pub position_side: String,
-    pub status: String,
-    pub stop_price: String,
+    pub side: Side,
+    pub position_side: PositionSide,
+    pub status: OrderStatus,
+    pub stop_price: Decimal,
     pub close_position: bool,
     pub symbol: String,
-    pub time_in_force: String,
-    pub r#type: String,
-    pub activate_price: Option<String>,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub activate_price: Option<Decimal>,
     pub price_rate: Option<String>,
     pub update_time: u64,
-    pub working_type: String,
+    pub working_type: WorkingType,
     pub price_protect: bool,
-    pub self_trade_prevention_mode: String,
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
     pub good_till_date: u64,
 }
 
 impl SignedRequest<Usdm> for CancelOrderRequest<'_> {
     const METHOD: Method = Method::DELETE;
     const ENDPOINT: &'static str = "/fapi/v1/order";
+    const ORDER_ENTRY: bool = true;
     type Response = CancelOrderResponse;
 
     fn timestamp(&self) -> u64 {
@@ -425,17 +727,17 @@ impl SignedRequest<Usdm> for CancelOrderRequest<'_> {
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UserCommissionRateRequest<'a> {
-    pub symbol: &'a str,
+    pub symbol: Cow<'a, str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recv_window: Option<u64>, // <= 60_000
     pub timestamp: u64,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct UserCommissionRateResponse {
     pub symbol: String,
     pub maker_commission_rate: String,
@@ -472,7 +774,7 @@ mod tests {
     async fn test_order_book_request() {
         let client = BinanceClient::usdm();
         let req = OrderBookRequest {
-            symbol: "BTCUSDT",
+            symbol: "BTCUSDT".into(),
             limit: Some(5),
         };
         let res = client.request(&req).await.unwrap();
@@ -482,7 +784,9 @@ mod tests {
     #[tokio::test]
     async fn test_price_ticker_request() {
         let client = BinanceClient::usdm();
-        let req = PriceTickerRequest { symbol: "BTCUSDT" };
+        let req = PriceTickerRequest {
+            symbol: "BTCUSDT".into(),
+        };
         let res = client.request(&req).await.unwrap();
         assert!(res.status.is_success());
     }
@@ -490,8 +794,102 @@ mod tests {
     #[tokio::test]
     async fn test_book_ticker_request() {
         let client = BinanceClient::usdm();
-        let req = BookTickerRequest { symbol: "BTCUSDT" };
+        let req = BookTickerRequest {
+            symbol: "BTCUSDT".into(),
+        };
         let res = client.request(&req).await.unwrap();
         assert!(res.status.is_success());
     }
+
+    #[test]
+    fn test_new_order_builder() {
+        use crate::validation::tests::param;
+        let order = NewOrder::close_position(
+            "BTCUSDT",
+            Side::Sell,
+            CloseTrigger::TakeProfitMarket,
+            param("70000").unwrap(),
+        )
+        .working_type(WorkingType::MarkPrice)
+        .build(1);
+        assert_eq!(
+            serde_qs::to_string(&order).unwrap(),
+            "symbol=BTCUSDT&side=SELL&type=TAKE_PROFIT_MARKET&stopPrice=70000&closePosition=true&workingType=MARK_PRICE&timestamp=1"
+        );
+        let order = NewOrder::limit(
+            "BTCUSDT",
+            Side::Buy,
+            param("0.01").unwrap(),
+            param("60000.1").unwrap(),
+            LimitTimeInForce::Ioc,
+        )
+        .build(1);
+        assert_eq!(
+            serde_qs::to_string(&order).unwrap(),
+            "symbol=BTCUSDT&side=BUY&type=LIMIT&timeInForce=IOC&quantity=0.01&price=60000.1&timestamp=1"
+        );
+        let order = NewOrder::post_only(
+            "BTCUSDT",
+            Side::Buy,
+            param("0.01").unwrap(),
+            param("60000.1").unwrap(),
+        )
+        .build(1);
+        assert_eq!(
+            serde_qs::to_string(&order).unwrap(),
+            "symbol=BTCUSDT&side=BUY&type=LIMIT&timeInForce=GTX&quantity=0.01&price=60000.1&timestamp=1"
+        );
+        let order = NewOrder::gtd(
+            "BTCUSDT",
+            Side::Buy,
+            param("0.01").unwrap(),
+            param("60000.1").unwrap(),
+            1700000000000,
+        )
+        .reduce_only()
+        .build(1);
+        assert_eq!(
+            serde_qs::to_string(&order).unwrap(),
+            "symbol=BTCUSDT&side=BUY&type=LIMIT&timeInForce=GTD&quantity=0.01&reduceOnly=true&price=60000.1&goodTillDate=1700000000000&timestamp=1"
+        );
+        let order = NewOrder::trailing_stop("BTCUSDT", Side::Sell, param("0.01").unwrap(), "1")
+            .activation_price(param("65000").unwrap())
+            .reduce_only()
+            .price_protect()
+            .build(1);
+        assert_eq!(
+            serde_qs::to_string(&order).unwrap(),
+            "symbol=BTCUSDT&side=SELL&type=TRAILING_STOP_MARKET&quantity=0.01&reduceOnly=true&activationPrice=65000&callbackRate=1&priceProtect=true&timestamp=1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_owned_request_across_tasks() {
+        use crate::validation::tests::param;
+        let (sender, mut receiver) = tokio::sync::mpsc::channel::<NewOrderRequest<'static>>(1);
+        let gateway = tokio::spawn(async move {
+            let order = receiver.recv().await.unwrap();
+            serde_qs::to_string(&order).unwrap()
+        });
+        let symbol = String::from("ETHUSDT");
+        let order = NewOrder::market(symbol, Side::Buy, param("1").unwrap())
+            .new_client_order_id(format!("retry-{}", 2))
+            .build(1);
+        sender.send(order).await.unwrap();
+        assert_eq!(
+            gateway.await.unwrap(),
+            "symbol=ETHUSDT&side=BUY&type=MARKET&quantity=1&newClientOrderId=retry-2&timestamp=1"
+        );
+    }
+
+    #[test]
+    fn test_response_round_trip() {
+        use crate::response::tests::assert_round_trip;
+        assert_round_trip::<OrderBookResponse>(
+            r#"{"lastUpdateId":1027024,"E":1589436922972,"T":1589436922959,"bids":[["4","431"]],"asks":[["4.000002","12"]]}"#,
+        );
+        assert_round_trip::<NewOrderResponse>(
+            r#"{"clientOrderId":"testOrder","cumQty":"0","cumQuote":"0","executedQty":"0","orderId":22542179,"avgPrice":"0","origQty":"10","price":"0","reduceOnly":false,"side":"BUY","positionSide":"SHORT","status":"NEW","stopPrice":"9300","closePosition":false,"symbol":"BTCUSDT","timeInForce":"GTD","type":"TRAILING_STOP_MARKET","origType":"TRAILING_STOP_MARKET","activatePrice":"9020","priceRate":"0.3","updateTime":1566818724722,"workingType":"CONTRACT_PRICE","priceProtect":false,"priceMatch":"NONE","selfTradePreventionMode":"NONE","goodTillDate":1693207680000}"#,
+        );
+    }
 }
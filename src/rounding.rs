@@ -0,0 +1,183 @@
+use crate::{
+    decimal::{Decimal, Fixed},
+    rest::filters::SymbolFilter,
+    validation::MarketRules,
+};
+use std::fmt;
+
+// Rounds prices and quantities so that they pass the symbol filters. The
+// maths is done on exact decimals, inputs that aren't plain decimal numbers
+// give `None`.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    Up,
+    Down,
+    Nearest,
+}
+
+pub trait SymbolRounding: MarketRules {
+    fn round_price(&self, price: impl fmt::Display, rounding: Rounding) -> Option<Decimal> {
+        let price = Fixed::from_display(price)?;
+        let rounded = match price_filter(self) {
+            Some((_, _, tick_size)) => price.round_to(&tick_size, rounding)?,
+            None => price,
+        };
+        to_decimal(rounded)
+    }
+
+    // Market orders follow the MARKET_LOT_SIZE step when there is one
+    fn floor_quantity(&self, quantity: impl fmt::Display, market_order: bool) -> Option<Decimal> {
+        let quantity = Fixed::from_display(quantity)?;
+        let floored = match lot_size(self, market_order) {
+            Some((_, _, step_size)) => quantity.round_to(&step_size, Rounding::Down)?,
+            None => quantity,
+        };
+        to_decimal(floored)
+    }
+
+    fn clamp_price(&self, price: impl fmt::Display) -> Option<Decimal> {
+        let price = Fixed::from_display(price)?;
+        let clamped = match price_filter(self) {
+            Some((min, max, _)) => clamp(price, min, max),
+            None => price,
+        };
+        to_decimal(clamped)
+    }
+
+    fn clamp_quantity(&self, quantity: impl fmt::Display, market_order: bool) -> Option<Decimal> {
+        let quantity = Fixed::from_display(quantity)?;
+        let clamped = match lot_size(self, market_order) {
+            Some((min, max, _)) => clamp(quantity, min, max),
+            None => quantity,
+        };
+        to_decimal(clamped)
+    }
+
+    // Smallest quantity on the lot step whose notional at `price` reaches the
+    // MIN_NOTIONAL or NOTIONAL minimum, and at least the lot minimum
+    fn min_notional_quantity(&self, price: impl fmt::Display) -> Option<Decimal> {
+        let price = Fixed::from_display(price)?;
+        let (min_qty, _, step_size) = lot_size(self, false).unwrap_or((None, None, Fixed::unit(0)));
+        let min_notional = self.filters().iter().find_map(|filter| match filter {
+            SymbolFilter::MinNotional { notional, .. } => nonzero(notional),
+            SymbolFilter::Notional { min_notional, .. } => nonzero(min_notional),
+            _ => None,
+        });
+        let quantity = match min_notional {
+            Some(min_notional) => price.min_multiple_reaching(&step_size, &min_notional)?,
+            None => step_size,
+        };
+        to_decimal(min_qty.map_or(quantity, |min_qty| quantity.max(min_qty)))
+    }
+
+    // Price with exactly `price_precision` decimals, or the tick size ones
+    fn format_price(&self, price: impl fmt::Display) -> Option<String> {
+        let scale = self
+            .price_precision()
+            .or_else(|| price_filter(self).map(|(_, _, tick_size)| tick_size.scale()))?;
+        format_scaled(price, scale, Rounding::Nearest)
+    }
+
+    // Quantity with exactly `quantity_precision` decimals, or the step size
+    // ones. Extra decimals are dropped rather than rounded up.
+    fn format_quantity(&self, quantity: impl fmt::Display) -> Option<String> {
+        let scale = self
+            .quantity_precision()
+            .or_else(|| lot_size(self, false).map(|(_, _, step_size)| step_size.scale()))?;
+        format_scaled(quantity, scale, Rounding::Down)
+    }
+}
+
+impl<M: MarketRules + ?Sized> SymbolRounding for M {}
+
+// A zero bound or step means the filter doesn't constrain it
+fn nonzero(value: &Decimal) -> Option<Fixed> {
+    Fixed::from_display(value).filter(|fixed| !fixed.is_zero())
+}
+
+fn price_filter<M: MarketRules + ?Sized>(
+    market: &M,
+) -> Option<(Option<Fixed>, Option<Fixed>, Fixed)> {
+    market.filters().iter().find_map(|filter| match filter {
+        SymbolFilter::PriceFilter {
+            min_price,
+            max_price,
+            tick_size,
+        } => Some((nonzero(min_price), nonzero(max_price), nonzero(tick_size)?)),
+        _ => None,
+    })
+}
+
+fn lot_size<M: MarketRules + ?Sized>(
+    market: &M,
+    market_order: bool,
+) -> Option<(Option<Fixed>, Option<Fixed>, Fixed)> {
+    let lot = |filter: &SymbolFilter| match filter {
+        SymbolFilter::LotSize {
+            min_qty,
+            max_qty,
+            step_size,
+        } if !market_order => Some((nonzero(min_qty), nonzero(max_qty), nonzero(step_size)?)),
+        SymbolFilter::MarketLotSize {
+            min_qty,
+            max_qty,
+            step_size,
+        } if market_order => Some((nonzero(min_qty), nonzero(max_qty), nonzero(step_size)?)),
+        _ => None,
+    };
+    market
+        .filters()
+        .iter()
+        .find_map(lot)
+        .or_else(|| market_order.then(|| lot_size(market, false)).flatten())
+}
+
+fn clamp(value: Fixed, min: Option<Fixed>, max: Option<Fixed>) -> Fixed {
+    let value = min.map_or(value, |min| value.max(min));
+    max.map_or(value, |max| value.min(max))
+}
+
+fn format_scaled(value: impl fmt::Display, scale: u32, rounding: Rounding) -> Option<String> {
+    Fixed::from_display(value)?
+        .round_to(&Fixed::unit(scale), rounding)?
+        .format_with_scale(scale)
+}
+
+fn to_decimal(value: Fixed) -> Option<Decimal> {
+    value.to_string().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::tests::market;
+
+    fn decimal(value: &str) -> Option<Decimal> {
+        value.parse().ok()
+    }
+
+    #[test]
+    fn test_rounding_helpers() {
+        let market = market();
+        assert_eq!(
+            market.round_price("60000.06", Rounding::Down),
+            decimal("60000")
+        );
+        assert_eq!(
+            market.round_price("60000.06", Rounding::Up),
+            decimal("60000.1")
+        );
+        assert_eq!(
+            market.round_price("60000.05", Rounding::Nearest),
+            decimal("60000.1")
+        );
+        assert_eq!(market.floor_quantity("0.0019", false), decimal("0.001"));
+        assert_eq!(market.clamp_quantity("500", true), decimal("120"));
+        assert_eq!(market.clamp_price("1"), decimal("556.8"));
+        assert_eq!(market.min_notional_quantity("60000"), decimal("0.002"));
+        assert_eq!(market.min_notional_quantity("200000"), decimal("0.001"));
+        assert_eq!(market.format_price("60000.1"), Some("60000.10".to_string()));
+        assert_eq!(market.format_quantity("0.0019"), Some("0.001".to_string()));
+    }
+}
@@ -0,0 +1,680 @@
+use crate::{
+    decimal::{Decimal, Fixed},
+    enums::{OrderType, Side, TimeInForce},
+    rest::{coinm, filters::SymbolFilter, spot, usdm},
+};
+use std::fmt;
+
+// Checks a new order against the symbol filters before it's sent, since
+// rejected orders still count against the order rate limits
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    SymbolMismatch {
+        market: String,
+        order: String,
+    },
+    OrderTypeNotAllowed(OrderType),
+    TimeInForceNotAllowed(TimeInForce),
+    MissingPrice,
+    MissingQuantity,
+    InvalidNumber(String),
+    PriceBelowMin {
+        price: String,
+        min: String,
+    },
+    PriceAboveMax {
+        price: String,
+        max: String,
+    },
+    PriceNotOnTick {
+        price: String,
+        tick_size: String,
+    },
+    PriceOutsideBand {
+        price: String,
+        min: String,
+        max: String,
+    },
+    QuantityBelowMin {
+        quantity: String,
+        min: String,
+    },
+    QuantityAboveMax {
+        quantity: String,
+        max: String,
+    },
+    QuantityNotOnStep {
+        quantity: String,
+        step_size: String,
+    },
+    NotionalBelowMin {
+        notional: String,
+        min: String,
+    },
+    NotionalAboveMax {
+        notional: String,
+        max: String,
+    },
+    TooManyOrders {
+        limit: u64,
+    },
+    TooManyAlgoOrders {
+        limit: u64,
+    },
+}
+
+pub trait MarketRules {
+    fn symbol(&self) -> &str;
+    fn filters(&self) -> &[SymbolFilter];
+    fn order_types(&self) -> &[OrderType];
+    // None if the market accepts any time in force
+    fn time_in_force(&self) -> Option<&[TimeInForce]>;
+    // Futures markets publish the decimals they accept, spot ones only the filters
+    fn price_precision(&self) -> Option<u32> {
+        None
+    }
+    fn quantity_precision(&self) -> Option<u32> {
+        None
+    }
+}
+
+pub trait PendingOrder {
+    fn symbol(&self) -> &str;
+    fn side(&self) -> &Side;
+    fn order_type(&self) -> &OrderType;
+    fn time_in_force(&self) -> Option<&TimeInForce>;
+    fn price(&self) -> Option<&dyn fmt::Display>;
+    fn stop_price(&self) -> Option<&dyn fmt::Display>;
+    fn quantity(&self) -> Option<&dyn fmt::Display>;
+    // Spot market orders can be sized in quote asset instead
+    fn quote_order_qty(&self) -> Option<&dyn fmt::Display> {
+        None
+    }
+    // Futures orders closing the whole position have no quantity
+    fn close_position(&self) -> bool {
+        false
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderValidator<'a, M> {
+    market: &'a M,
+    reference_price: Option<String>,
+    open_orders: Option<u64>,
+    open_algo_orders: Option<u64>,
+}
+
+impl<'a, M: MarketRules> OrderValidator<'a, M> {
+    pub fn new(market: &'a M) -> Self {
+        Self {
+            market,
+            reference_price: None,
+            open_orders: None,
+            open_algo_orders: None,
+        }
+    }
+
+    // Mark price on futures, last or average price on spot. Percent price
+    // bands and the notional of market orders are only checked with one.
+    pub fn with_reference_price(mut self, price: impl fmt::Display) -> Self {
+        self.reference_price = Some(price.to_string());
+        self
+    }
+
+    // Orders currently open on the symbol, checked against the max orders filters
+    pub fn with_open_orders(mut self, open_orders: u64, open_algo_orders: u64) -> Self {
+        self.open_orders = Some(open_orders);
+        self.open_algo_orders = Some(open_algo_orders);
+        self
+    }
+
+    pub fn validate<O: PendingOrder>(&self, order: &O) -> Result<(), Vec<Violation>> {
+        let mut violations = Vec::new();
+
+        if order.symbol() != self.market.symbol() {
+            violations.push(Violation::SymbolMismatch {
+                market: self.market.symbol().to_string(),
+                order: order.symbol().to_string(),
+            });
+        }
+        let order_type = order.order_type();
+        if !self.market.order_types().contains(order_type) {
+            violations.push(Violation::OrderTypeNotAllowed(order_type.clone()));
+        }
+        if let (Some(time_in_force), Some(allowed)) =
+            (order.time_in_force(), self.market.time_in_force())
+        {
+            if !allowed.contains(time_in_force) {
+                violations.push(Violation::TimeInForceNotAllowed(time_in_force.clone()));
+            }
+        }
+
+        let mut parse = |value: Option<&dyn fmt::Display>| {
+            let value = value?.to_string();
+            let fixed = Fixed::parse(&value);
+            if fixed.is_none() {
+                violations.push(Violation::InvalidNumber(value));
+            }
+            fixed
+        };
+        let price = parse(order.price());
+        let stop_price = parse(order.stop_price());
+        let quantity = parse(order.quantity());
+        let quote_order_qty = parse(order.quote_order_qty());
+        let reference_price = parse(
+            self.reference_price
+                .as_ref()
+                .map(|p| p as &dyn fmt::Display),
+        );
+
+        let is_market = !has_limit_price(order_type);
+        if !is_market && price.is_none() {
+            violations.push(Violation::MissingPrice);
+        }
+        if quantity.is_none() && quote_order_qty.is_none() && !order.close_position() {
+            violations.push(Violation::MissingQuantity);
+        }
+
+        // Price of a market order is only known through the reference price
+        let execution_price = if is_market { reference_price } else { price };
+        let notional = match (quantity, quote_order_qty, execution_price) {
+            (_, Some(quote_order_qty), _) => Some(quote_order_qty),
+            (Some(quantity), _, Some(price)) => quantity.checked_mul(&price),
+            _ => None,
+        };
+
+        for filter in self.market.filters() {
+            match filter {
+                SymbolFilter::PriceFilter {
+                    min_price,
+                    max_price,
+                    tick_size,
+                } => {
+                    for price in price.iter().chain(stop_price.iter()) {
+                        check_price(price, min_price, max_price, tick_size, &mut violations);
+                    }
+                }
+                SymbolFilter::PercentPrice {
+                    multiplier_up,
+                    multiplier_down,
+                    ..
+                } => check_band(
+                    price,
+                    reference_price,
+                    multiplier_down,
+                    multiplier_up,
+                    &mut violations,
+                ),
+                SymbolFilter::PercentPriceBySide {
+                    bid_multiplier_up,
+                    bid_multiplier_down,
+                    ask_multiplier_up,
+                    ask_multiplier_down,
+                    ..
+                } => {
+                    let (down, up) = match order.side() {
+                        Side::Sell => (ask_multiplier_down, ask_multiplier_up),
+                        _ => (bid_multiplier_down, bid_multiplier_up),
+                    };
+                    check_band(price, reference_price, down, up, &mut violations)
+                }
+                SymbolFilter::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    if let Some(quantity) = quantity {
+                        check_quantity(&quantity, min_qty, max_qty, step_size, &mut violations);
+                    }
+                }
+                SymbolFilter::MarketLotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } if is_market => {
+                    if let Some(quantity) = quantity {
+                        check_quantity(&quantity, min_qty, max_qty, step_size, &mut violations);
+                    }
+                }
+                SymbolFilter::MinNotional {
+                    notional: min,
+                    apply_to_market,
+                    ..
+                } if !is_market || apply_to_market.unwrap_or(true) => {
+                    check_notional(notional, Some(min), None, &mut violations);
+                }
+                SymbolFilter::Notional {
+                    min_notional,
+                    apply_min_to_market,
+                    max_notional,
+                    apply_max_to_market,
+                    ..
+                } => check_notional(
+                    notional,
+                    (!is_market || *apply_min_to_market).then_some(min_notional),
+                    (!is_market || *apply_max_to_market).then_some(max_notional),
+                    &mut violations,
+                ),
+                SymbolFilter::MaxNumOrders { limit }
+                    if self.open_orders.is_some_and(|open| open >= *limit) =>
+                {
+                    violations.push(Violation::TooManyOrders { limit: *limit });
+                }
+                SymbolFilter::MaxNumAlgoOrders { limit }
+                    if is_algo(order_type)
+                        && self.open_algo_orders.is_some_and(|open| open >= *limit) =>
+                {
+                    violations.push(Violation::TooManyAlgoOrders { limit: *limit });
+                }
+                _ => {}
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+fn has_limit_price(order_type: &OrderType) -> bool {
+    matches!(
+        order_type,
+        OrderType::Limit
+            | OrderType::LimitMaker
+            | OrderType::StopLossLimit
+            | OrderType::TakeProfitLimit
+            | OrderType::Stop
+            | OrderType::TakeProfit
+    )
+}
+
+fn is_algo(order_type: &OrderType) -> bool {
+    matches!(
+        order_type,
+        OrderType::StopLoss
+            | OrderType::StopLossLimit
+            | OrderType::TakeProfit
+            | OrderType::TakeProfitLimit
+            | OrderType::Stop
+            | OrderType::StopMarket
+            | OrderType::TakeProfitMarket
+            | OrderType::TrailingStopMarket
+    )
+}
+
+// A zero bound or step disables that part of the filter
+fn bound(value: &Decimal) -> Option<Fixed> {
+    Fixed::from_display(value).filter(|fixed| !fixed.is_zero())
+}
+
+fn check_price(
+    price: &Fixed,
+    min: &Decimal,
+    max: &Decimal,
+    tick_size: &Decimal,
+    violations: &mut Vec<Violation>,
+) {
+    if let Some(min) = bound(min).filter(|min| price < min) {
+        violations.push(Violation::PriceBelowMin {
+            price: price.to_string(),
+            min: min.to_string(),
+        });
+    }
+    if let Some(max) = bound(max).filter(|max| price > max) {
+        violations.push(Violation::PriceAboveMax {
+            price: price.to_string(),
+            max: max.to_string(),
+        });
+    }
+    if let Some(tick_size) = bound(tick_size) {
+        if price.is_multiple_of(&tick_size) == Some(false) {
+            violations.push(Violation::PriceNotOnTick {
+                price: price.to_string(),
+                tick_size: tick_size.to_string(),
+            });
+        }
+    }
+}
+
+fn check_band(
+    price: Option<Fixed>,
+    reference_price: Option<Fixed>,
+    multiplier_down: &Decimal,
+    multiplier_up: &Decimal,
+    violations: &mut Vec<Violation>,
+) {
+    let (Some(price), Some(reference_price)) = (price, reference_price) else {
+        return;
+    };
+    let min = Fixed::from_display(multiplier_down).and_then(|m| m.checked_mul(&reference_price));
+    let max = Fixed::from_display(multiplier_up).and_then(|m| m.checked_mul(&reference_price));
+    if let (Some(min), Some(max)) = (min, max) {
+        if price < min || price > max {
+            violations.push(Violation::PriceOutsideBand {
+                price: price.to_string(),
+                min: min.to_string(),
+                max: max.to_string(),
+            });
+        }
+    }
+}
+
+fn check_quantity(
+    quantity: &Fixed,
+    min: &Decimal,
+    max: &Decimal,
+    step_size: &Decimal,
+    violations: &mut Vec<Violation>,
+) {
+    if let Some(min) = bound(min).filter(|min| quantity < min) {
+        violations.push(Violation::QuantityBelowMin {
+            quantity: quantity.to_string(),
+            min: min.to_string(),
+        });
+    }
+    if let Some(max) = bound(max).filter(|max| quantity > max) {
+        violations.push(Violation::QuantityAboveMax {
+            quantity: quantity.to_string(),
+            max: max.to_string(),
+        });
+    }
+    if let Some(step_size) = bound(step_size) {
+        if quantity.is_multiple_of(&step_size) == Some(false) {
+            violations.push(Violation::QuantityNotOnStep {
+                quantity: quantity.to_string(),
+                step_size: step_size.to_string(),
+            });
+        }
+    }
+}
+
+fn check_notional(
+    notional: Option<Fixed>,
+    min: Option<&Decimal>,
+    max: Option<&Decimal>,
+    violations: &mut Vec<Violation>,
+) {
+    let Some(notional) = notional else {
+        return;
+    };
+    if let Some(min) = min.and_then(bound).filter(|min| notional < *min) {
+        violations.push(Violation::NotionalBelowMin {
+            notional: notional.to_string(),
+            min: min.to_string(),
+        });
+    }
+    if let Some(max) = max.and_then(bound).filter(|max| notional > *max) {
+        violations.push(Violation::NotionalAboveMax {
+            notional: notional.to_string(),
+            max: max.to_string(),
+        });
+    }
+}
+
+impl MarketRules for spot::Market {
+    fn symbol(&self) -> &str {
+        &self.symbol
+    }
+    fn filters(&self) -> &[SymbolFilter] {
+        &self.filters
+    }
+    fn order_types(&self) -> &[OrderType] {
+        &self.order_types
+    }
+    // Spot exchange info doesn't list them, every spot symbol takes the same
+    fn time_in_force(&self) -> Option<&[TimeInForce]> {
+        const SPOT: &[TimeInForce] = &[TimeInForce::Gtc, TimeInForce::Ioc, TimeInForce::Fok];
+        Some(SPOT)
+    }
+}
+
+impl MarketRules for usdm::Market {
+    fn symbol(&self) -> &str {
+        &self.symbol
+    }
+    fn filters(&self) -> &[SymbolFilter] {
+        &self.filters
+    }
+    fn order_types(&self) -> &[OrderType] {
+        &self.order_types
+    }
+    fn time_in_force(&self) -> Option<&[TimeInForce]> {
+        Some(&self.time_in_force)
+    }
+    fn price_precision(&self) -> Option<u32> {
+        Some(self.price_precision as u32)
+    }
+    fn quantity_precision(&self) -> Option<u32> {
+        Some(self.quantity_precision as u32)
+    }
+}
+
+impl MarketRules for coinm::Market {
+    fn symbol(&self) -> &str {
+        &self.symbol
+    }
+    fn filters(&self) -> &[SymbolFilter] {
+        &self.filters
+    }
+    fn order_types(&self) -> &[OrderType] {
+        &self.order_types
+    }
+    fn time_in_force(&self) -> Option<&[TimeInForce]> {
+        Some(&self.time_in_force)
+    }
+    fn price_precision(&self) -> Option<u32> {
+        Some(self.price_precision as u32)
+    }
+    fn quantity_precision(&self) -> Option<u32> {
+        Some(self.quantity_precision as u32)
+    }
+}
+
+impl PendingOrder for spot::NewOrderRequest<'_> {
+    fn symbol(&self) -> &str {
+        &self.symbol
+    }
+    fn side(&self) -> &Side {
+        &self.side
+    }
+    fn order_type(&self) -> &OrderType {
+        &self.r#type
+    }
+    fn time_in_force(&self) -> Option<&TimeInForce> {
+        self.time_in_force.as_ref()
+    }
+    fn price(&self) -> Option<&dyn fmt::Display> {
+        self.price.as_ref().map(|v| v as _)
+    }
+    fn stop_price(&self) -> Option<&dyn fmt::Display> {
+        self.stop_price.as_ref().map(|v| v as _)
+    }
+    fn quantity(&self) -> Option<&dyn fmt::Display> {
+        self.quantity.as_ref().map(|v| v as _)
+    }
+    fn quote_order_qty(&self) -> Option<&dyn fmt::Display> {
+        self.quote_order_qty.as_ref().map(|v| v as _)
+    }
+}
+
+impl PendingOrder for usdm::NewOrderRequest<'_> {
+    fn symbol(&self) -> &str {
+        &self.symbol
+    }
+    fn side(&self) -> &Side {
+        &self.side
+    }
+    fn order_type(&self) -> &OrderType {
+        &self.r#type
+    }
+    fn time_in_force(&self) -> Option<&TimeInForce> {
+        self.time_in_force.as_ref()
+    }
+    fn price(&self) -> Option<&dyn fmt::Display> {
+        self.price.as_ref().map(|v| v as _)
+    }
+    fn stop_price(&self) -> Option<&dyn fmt::Display> {
+        self.stop_price.as_ref().map(|v| v as _)
+    }
+    fn quantity(&self) -> Option<&dyn fmt::Display> {
+        self.quantity.as_ref().map(|v| v as _)
+    }
+    fn close_position(&self) -> bool {
+        self.close_position.unwrap_or(false)
+    }
+}
+
+impl PendingOrder for coinm::NewOrderRequest<'_> {
+    fn symbol(&self) -> &str {
+        &self.symbol
+    }
+    fn side(&self) -> &Side {
+        &self.side
+    }
+    fn order_type(&self) -> &OrderType {
+        &self.r#type
+    }
+    fn time_in_force(&self) -> Option<&TimeInForce> {
+        self.time_in_force.as_ref()
+    }
+    fn price(&self) -> Option<&dyn fmt::Display> {
+        self.price.as_ref().map(|v| v as _)
+    }
+    fn stop_price(&self) -> Option<&dyn fmt::Display> {
+        self.stop_price.as_ref().map(|v| v as _)
+    }
+    fn quantity(&self) -> Option<&dyn fmt::Display> {
+        self.quantity.as_ref().map(|v| v as _)
+    }
+    fn close_position(&self) -> bool {
+        self.close_position.unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn param(value: &'static str) -> Option<crate::decimal::DecimalParam<'static>> {
+        crate::decimal::decimal_param(value)
+    }
+
+    pub(crate) fn market() -> usdm::Market {
+        serde_json::from_str(
+            r#"{
+                "symbol": "BTCUSDT", "pair": "BTCUSDT", "contractType": "PERPETUAL",
+                "deliveryDate": 4133404800000, "onboardDate": 1569398400000, "status": "TRADING",
+                "maintMarginPercent": "2.5000", "requiredMarginPercent": "5.0000",
+                "baseAsset": "BTC", "quoteAsset": "USDT", "marginAsset": "USDT",
+                "pricePrecision": 2, "quantityPrecision": 3, "baseAssetPrecision": 8,
+                "quotePrecision": 8, "underlyingType": "COIN", "underlyingSubType": ["PoW"],
+                "triggerProtect": "0.0500", "liquidationFee": "0.012500", "marketTakeBound": "0.05",
+                "filters": [
+                    {"filterType": "PRICE_FILTER", "minPrice": "556.80", "maxPrice": "4529764", "tickSize": "0.10"},
+                    {"filterType": "LOT_SIZE", "minQty": "0.001", "maxQty": "1000", "stepSize": "0.001"},
+                    {"filterType": "MARKET_LOT_SIZE", "minQty": "0.001", "maxQty": "120", "stepSize": "0.001"},
+                    {"filterType": "MAX_NUM_ORDERS", "limit": 200},
+                    {"filterType": "MIN_NOTIONAL", "notional": "100"},
+                    {"filterType": "PERCENT_PRICE", "multiplierUp": "1.0500", "multiplierDown": "0.9500", "multiplierDecimal": "4"}
+                ],
+                "orderTypes": ["LIMIT", "MARKET", "STOP", "STOP_MARKET", "TAKE_PROFIT", "TAKE_PROFIT_MARKET", "TRAILING_STOP_MARKET"],
+                "timeInForce": ["GTC", "IOC", "FOK", "GTX", "GTD"]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_order_validation() {
+        let market = market();
+        let validator = OrderValidator::new(&market)
+            .with_reference_price("60000")
+            .with_open_orders(200, 0);
+        let mut order = usdm::NewOrderRequest {
+            symbol: "BTCUSDT".into(),
+            side: Side::Buy,
+            position_side: None,
+            r#type: OrderType::Limit,
+            time_in_force: Some(TimeInForce::Gtc),
+            quantity: param("0.0015"),
+            reduce_only: None,
+            price: param("70000.05"),
+            new_client_order_id: None,
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+            self_trade_prevention_mode: None,
+            good_till_date: None,
+            recv_window: None,
+            timestamp: 0,
+        };
+        assert_eq!(
+            validator.validate(&order),
+            Err(vec![
+                Violation::PriceNotOnTick {
+                    price: "70000.05".to_string(),
+                    tick_size: "0.1".to_string(),
+                },
+                Violation::QuantityNotOnStep {
+                    quantity: "0.0015".to_string(),
+                    step_size: "0.001".to_string(),
+                },
+                Violation::TooManyOrders { limit: 200 },
+                Violation::PriceOutsideBand {
+                    price: "70000.05".to_string(),
+                    min: "57000".to_string(),
+                    max: "63000".to_string(),
+                },
+            ])
+        );
+
+        order.price = param("60000.1");
+        order.quantity = param("0.002");
+        assert_eq!(
+            OrderValidator::new(&market)
+                .with_reference_price("60000")
+                .validate(&order),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_spot_time_in_force() {
+        let market: spot::Market = serde_json::from_str(
+            r#"{
+                "symbol": "BTCUSDT", "status": "TRADING", "baseAsset": "BTC", "baseAssetPrecision": 8,
+                "quoteAsset": "USDT", "quotePrecision": 8, "quoteAssetPrecision": 8,
+                "baseCommissionPrecision": 8, "quoteCommissionPrecision": 8,
+                "orderTypes": ["LIMIT", "LIMIT_MAKER", "MARKET"], "icebergAllowed": true,
+                "ocoAllowed": true, "quoteOrderQtyMarketAllowed": true, "allowTrailingStop": true,
+                "cancelReplaceAllowed": true, "isSpotTradingAllowed": true,
+                "isMarginTradingAllowed": true, "filters": [], "permissions": [],
+                "defaultSelfTradePreventionMode": "EXPIRE_MAKER",
+                "allowedSelfTradePreventionModes": ["EXPIRE_MAKER"]
+            }"#,
+        )
+        .unwrap();
+        let validator = OrderValidator::new(&market);
+        let order = |time_in_force| {
+            spot::NewOrder::limit(
+                "BTCUSDT",
+                Side::Buy,
+                param("1").unwrap(),
+                param("60000").unwrap(),
+                time_in_force,
+            )
+            .build(0)
+        };
+        assert_eq!(validator.validate(&order(TimeInForce::Fok)), Ok(()));
+        assert_eq!(
+            validator.validate(&order(TimeInForce::Gtx)),
+            Err(vec![Violation::TimeInForceNotAllowed(TimeInForce::Gtx)])
+        );
+    }
+}
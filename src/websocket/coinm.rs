@@ -0,0 +1,821 @@
+use crate::{
+    client::Coinm,
+    decimal::Decimal,
+    enums::{
+        ExecutionType, OrderStatus, OrderType, PositionSide, PriceMatch, SelfTradePreventionMode,
+        Side, TimeInForce, WorkingType,
+    },
+};
+
+use super::StreamTopic;
+use binance_async_api_macros::wire_names;
+use serde::{Deserialize, Serialize};
+
+pub use super::usdm::{
+    BalanceUpdate, BookLevelUpdate, ContractBracket, ContractInfoEvent, LeverageUpdate,
+    ListenKeyExpiredEvent, PositionMarginCall, PositionUpdate,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct AggTradeStream<'a> {
+    pub symbol: &'a str,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AggTradeEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "a"]
+    pub id: u64,
+    #[wire = "p"]
+    pub price: Decimal,
+    #[wire = "q"]
+    pub qty: Decimal, // in contracts
+    #[wire = "f"]
+    pub first_trade_id: u64,
+    #[wire = "l"]
+    pub last_trade_id: u64,
+    #[wire = "T"]
+    pub trade_time: u64,
+    #[wire = "m"]
+    pub buyer_is_maker: bool,
+}
+
+impl StreamTopic<Coinm> for AggTradeStream<'_> {
+    fn endpoint(&self) -> String {
+        format!("/ws/{}@aggTrade", self.symbol.to_lowercase())
+    }
+    type Event = AggTradeEvent;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum PriceUpdateSpeed {
+    Ms1000,
+    Ms3000,
+}
+
+impl PriceUpdateSpeed {
+    fn suffix(&self) -> &'static str {
+        match self {
+            PriceUpdateSpeed::Ms1000 => "@1s",
+            PriceUpdateSpeed::Ms3000 => "",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct IndexPriceStream<'a> {
+    pub pair: &'a str,
+    pub update_speed: PriceUpdateSpeed,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IndexPriceEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "i"]
+    pub pair: String,
+    #[wire = "p"]
+    pub index_price: Decimal,
+}
+
+impl StreamTopic<Coinm> for IndexPriceStream<'_> {
+    fn endpoint(&self) -> String {
+        format!(
+            "/ws/{}@indexPrice{}",
+            self.pair.to_lowercase(),
+            self.update_speed.suffix()
+        )
+    }
+    type Event = IndexPriceEvent;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MarkPriceStream<'a> {
+    pub symbol: &'a str,
+    pub update_speed: PriceUpdateSpeed,
+}
+
+// Every symbol of the pair, perpetual and delivery contracts alike
+#[derive(Debug, Clone, Copy)]
+pub struct PairMarkPriceStream<'a> {
+    pub pair: &'a str,
+    pub update_speed: PriceUpdateSpeed,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MarkPriceEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "p"]
+    pub mark_price: Decimal,
+    #[wire = "P"]
+    pub estimated_settle_price: Decimal,
+    #[wire = "i"]
+    pub index_price: Option<Decimal>,
+    #[wire = "r"]
+    pub funding_rate: String, // empty for delivery contracts
+    #[wire = "T"]
+    pub next_funding_time: u64,
+}
+
+impl StreamTopic<Coinm> for MarkPriceStream<'_> {
+    fn endpoint(&self) -> String {
+        format!(
+            "/ws/{}@markPrice{}",
+            self.symbol.to_lowercase(),
+            self.update_speed.suffix()
+        )
+    }
+    type Event = MarkPriceEvent;
+}
+
+impl StreamTopic<Coinm> for PairMarkPriceStream<'_> {
+    fn endpoint(&self) -> String {
+        format!(
+            "/ws/{}@markPrice{}",
+            self.pair.to_lowercase(),
+            self.update_speed.suffix()
+        )
+    }
+    type Event = Vec<MarkPriceEvent>;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct KlineStream<'a> {
+    pub symbol: &'a str,
+    pub interval: &'a str,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct KlineEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "k"]
+    pub kline: Kline,
+}
+
+// Index and mark price klines leave the trade and volume fields empty
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Kline {
+    #[wire = "t"]
+    pub open_time: u64,
+    #[wire = "T"]
+    pub close_time: u64,
+    #[wire = "s"]
+    pub symbol: Option<String>,
+    #[wire = "i"]
+    pub interval: String,
+    #[wire = "f"]
+    pub first_trade_id: i64,
+    #[wire = "L"]
+    pub last_trade_id: i64,
+    #[wire = "o"]
+    pub open_price: Decimal,
+    #[wire = "c"]
+    pub close_price: Decimal,
+    #[wire = "h"]
+    pub high_price: Decimal,
+    #[wire = "l"]
+    pub low_price: Decimal,
+    #[wire = "v"]
+    pub volume: Decimal, // in contracts
+    #[wire = "n"]
+    pub trade_count: u64,
+    #[wire = "x"]
+    pub is_closed: bool,
+    #[wire = "q"]
+    pub base_volume: Decimal,
+    #[wire = "V"]
+    pub taker_buy_volume: Decimal,
+    #[wire = "Q"]
+    pub taker_buy_base_volume: Decimal,
+}
+
+impl StreamTopic<Coinm> for KlineStream<'_> {
+    fn endpoint(&self) -> String {
+        format!("/ws/{}@kline_{}", self.symbol.to_lowercase(), self.interval)
+    }
+    type Event = KlineEvent;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ContinuousKlineStream<'a> {
+    pub pair: &'a str,
+    pub contract_type: &'a str, // perpetual, current_quarter or next_quarter
+    pub interval: &'a str,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ContinuousKlineEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "ps"]
+    pub pair: String,
+    #[wire = "ct"]
+    pub contract_type: String,
+    #[wire = "k"]
+    pub kline: Kline,
+}
+
+impl StreamTopic<Coinm> for ContinuousKlineStream<'_> {
+    fn endpoint(&self) -> String {
+        format!(
+            "/ws/{}_{}@continuousKline_{}",
+            self.pair.to_lowercase(),
+            self.contract_type.to_lowercase(),
+            self.interval
+        )
+    }
+    type Event = ContinuousKlineEvent;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct IndexPriceKlineStream<'a> {
+    pub pair: &'a str,
+    pub interval: &'a str,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MarkPriceKlineStream<'a> {
+    pub symbol: &'a str,
+    pub interval: &'a str,
+}
+
+// `ps` holds the pair for index price klines and the symbol for mark price klines
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PriceKlineEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "ps"]
+    pub pair: String,
+    #[wire = "k"]
+    pub kline: Kline,
+}
+
+impl StreamTopic<Coinm> for IndexPriceKlineStream<'_> {
+    fn endpoint(&self) -> String {
+        format!(
+            "/ws/{}@indexPriceKline_{}",
+            self.pair.to_lowercase(),
+            self.interval
+        )
+    }
+    type Event = PriceKlineEvent;
+}
+
+impl StreamTopic<Coinm> for MarkPriceKlineStream<'_> {
+    fn endpoint(&self) -> String {
+        format!(
+            "/ws/{}@markPriceKline_{}",
+            self.symbol.to_lowercase(),
+            self.interval
+        )
+    }
+    type Event = PriceKlineEvent;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MiniTickerStream<'a> {
+    pub symbol: &'a str,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AllMiniTickersStream;
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MiniTickerEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "ps"]
+    pub pair: String,
+    #[wire = "c"]
+    pub close_price: Decimal,
+    #[wire = "o"]
+    pub open_price: Decimal,
+    #[wire = "h"]
+    pub high_price: Decimal,
+    #[wire = "l"]
+    pub low_price: Decimal,
+    #[wire = "v"]
+    pub volume: Decimal, // in contracts
+    #[wire = "q"]
+    pub base_volume: Decimal,
+}
+
+impl StreamTopic<Coinm> for MiniTickerStream<'_> {
+    fn endpoint(&self) -> String {
+        format!("/ws/{}@miniTicker", self.symbol.to_lowercase())
+    }
+    type Event = MiniTickerEvent;
+}
+
+impl StreamTopic<Coinm> for AllMiniTickersStream {
+    fn endpoint(&self) -> String {
+        "/ws/!miniTicker@arr".to_string()
+    }
+    type Event = Vec<MiniTickerEvent>;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TickerStream<'a> {
+    pub symbol: &'a str,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AllTickersStream;
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TickerEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "ps"]
+    pub pair: String,
+    #[wire = "p"]
+    pub price_change: Decimal,
+    #[wire = "P"]
+    pub price_change_percent: String,
+    #[wire = "w"]
+    pub weighted_avg_price: Decimal,
+    #[wire = "c"]
+    pub last_price: Decimal,
+    #[wire = "Q"]
+    pub last_qty: Decimal,
+    #[wire = "o"]
+    pub open_price: Decimal,
+    #[wire = "h"]
+    pub high_price: Decimal,
+    #[wire = "l"]
+    pub low_price: Decimal,
+    #[wire = "v"]
+    pub volume: Decimal, // in contracts
+    #[wire = "q"]
+    pub base_volume: Decimal,
+    #[wire = "O"]
+    pub open_time: u64,
+    #[wire = "C"]
+    pub close_time: u64,
+    #[wire = "F"]
+    pub first_trade_id: i64,
+    #[wire = "L"]
+    pub last_trade_id: i64,
+    #[wire = "n"]
+    pub trade_count: u64,
+}
+
+impl StreamTopic<Coinm> for TickerStream<'_> {
+    fn endpoint(&self) -> String {
+        format!("/ws/{}@ticker", self.symbol.to_lowercase())
+    }
+    type Event = TickerEvent;
+}
+
+impl StreamTopic<Coinm> for AllTickersStream {
+    fn endpoint(&self) -> String {
+        "/ws/!ticker@arr".to_string()
+    }
+    type Event = Vec<TickerEvent>;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BookTickerStream<'a> {
+    pub symbol: &'a str,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AllBookTickersStream;
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BookTickerEvent {
+    #[wire = "u"]
+    pub order_book_update_id: u64,
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "T"]
+    pub transaction_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "ps"]
+    pub pair: String,
+    #[wire = "b"]
+    pub best_bid_price: Decimal,
+    #[wire = "B"]
+    pub best_bid_qty: Decimal,
+    #[wire = "a"]
+    pub best_ask_price: Decimal,
+    #[wire = "A"]
+    pub best_ask_qty: Decimal,
+}
+
+impl StreamTopic<Coinm> for BookTickerStream<'_> {
+    fn endpoint(&self) -> String {
+        format!("/ws/{}@bookTicker", self.symbol.to_lowercase())
+    }
+    type Event = BookTickerEvent;
+}
+
+impl StreamTopic<Coinm> for AllBookTickersStream {
+    fn endpoint(&self) -> String {
+        "/ws/!bookTicker".to_string()
+    }
+    type Event = BookTickerEvent;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LiquidationStream<'a> {
+    pub symbol: &'a str,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AllLiquidationsStream;
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LiquidationEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "o"]
+    pub order: LiquidationOrder,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LiquidationOrder {
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "ps"]
+    pub pair: String,
+    #[wire = "S"]
+    pub side: Side,
+    #[wire = "o"]
+    pub order_type: OrderType,
+    #[wire = "f"]
+    pub time_in_force: TimeInForce,
+    #[wire = "q"]
+    pub orig_qty: Decimal,
+    #[wire = "p"]
+    pub price: Decimal,
+    #[wire = "ap"]
+    pub average_price: Decimal,
+    #[wire = "X"]
+    pub order_status: OrderStatus,
+    #[wire = "l"]
+    pub last_filled_qty: Decimal,
+    #[wire = "z"]
+    pub cummulative_filled_qty: Decimal,
+    #[wire = "T"]
+    pub trade_time: u64,
+}
+
+impl StreamTopic<Coinm> for LiquidationStream<'_> {
+    fn endpoint(&self) -> String {
+        format!("/ws/{}@forceOrder", self.symbol.to_lowercase())
+    }
+    type Event = LiquidationEvent;
+}
+
+// Despite the name, every message holds a single liquidation
+impl StreamTopic<Coinm> for AllLiquidationsStream {
+    fn endpoint(&self) -> String {
+        "/ws/!forceOrder@arr".to_string()
+    }
+    type Event = LiquidationEvent;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum DepthUpdateSpeed {
+    Ms100,
+    Ms250,
+    Ms500,
+}
+
+impl DepthUpdateSpeed {
+    fn suffix(&self) -> &'static str {
+        match self {
+            DepthUpdateSpeed::Ms100 => "@100ms",
+            DepthUpdateSpeed::Ms250 => "",
+            DepthUpdateSpeed::Ms500 => "@500ms",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum PartialDepthLevels {
+    Five = 5,
+    Ten = 10,
+    Twenty = 20,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PartialDepthStream<'a> {
+    pub symbol: &'a str,
+    pub levels: PartialDepthLevels,
+    pub update_speed: DepthUpdateSpeed,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DiffDepthStream<'a> {
+    pub symbol: &'a str,
+    pub update_speed: DepthUpdateSpeed,
+}
+
+// Partial depth events carry the top levels instead of updates
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DepthEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "T"]
+    pub transaction_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "ps"]
+    pub pair: String,
+    #[wire = "U"]
+    pub first_update_id: u64,
+    #[wire = "u"]
+    pub final_update_id: u64,
+    #[wire = "pu"]
+    pub last_event_final_update_id: u64,
+    #[wire = "b"]
+    pub bid_updates: Vec<BookLevelUpdate>,
+    #[wire = "a"]
+    pub ask_updates: Vec<BookLevelUpdate>,
+}
+
+impl StreamTopic<Coinm> for PartialDepthStream<'_> {
+    fn endpoint(&self) -> String {
+        format!(
+            "/ws/{}@depth{}{}",
+            self.symbol.to_lowercase(),
+            self.levels as u64,
+            self.update_speed.suffix()
+        )
+    }
+    type Event = DepthEvent;
+}
+
+impl StreamTopic<Coinm> for DiffDepthStream<'_> {
+    fn endpoint(&self) -> String {
+        format!(
+            "/ws/{}@depth{}",
+            self.symbol.to_lowercase(),
+            self.update_speed.suffix()
+        )
+    }
+    type Event = DepthEvent;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ContractInfoStream;
+
+impl StreamTopic<Coinm> for ContractInfoStream {
+    fn endpoint(&self) -> String {
+        "/ws/!contractInfo".to_string()
+    }
+    type Event = ContractInfoEvent;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct UserStream<'a> {
+    pub listen_key: &'a str,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MarginCallEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "i"]
+    pub account_alias: String,
+    #[wire = "cw"]
+    pub cross_wallet_balance: Option<Decimal>,
+    #[wire = "p"]
+    pub positions: Vec<PositionMarginCall>,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BalancePositionUpdateEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "T"]
+    pub transaction_time: u64,
+    #[wire = "i"]
+    pub account_alias: String,
+    #[wire = "a"]
+    pub balance_position_update: BalancePositionUpdate,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BalancePositionUpdate {
+    #[wire = "m"]
+    pub reason: String,
+    #[wire = "B"]
+    pub balance_updates: Vec<BalanceUpdate>,
+    #[wire = "P"]
+    pub position_updates: Vec<PositionUpdate>, // amounts in contracts
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrderUpdateEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "T"]
+    pub transaction_time: u64,
+    #[wire = "i"]
+    pub account_alias: String,
+    #[wire = "o"]
+    pub order_update: OrderUpdate,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrderUpdate {
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "c"]
+    pub client_order_id: String,
+    #[wire = "S"]
+    pub side: Side,
+    #[wire = "o"]
+    pub order_type: OrderType,
+    #[wire = "f"]
+    pub time_in_force: TimeInForce,
+    #[wire = "q"]
+    pub orig_qty: Decimal, // in contracts
+    #[wire = "p"]
+    pub orig_price: Decimal,
+    #[wire = "ap"]
+    pub average_price: Decimal,
+    #[wire = "sp"]
+    pub stop_price: Decimal,
+    #[wire = "x"]
+    pub current_order_execution_type: ExecutionType,
+    #[wire = "X"]
+    pub current_order_status: OrderStatus,
+    #[wire = "i"]
+    pub order_id: u64,
+    #[wire = "l"]
+    pub last_filled_qty: Decimal,
+    #[wire = "z"]
+    pub cummulative_filled_qty: Decimal,
+    #[wire = "L"]
+    pub last_fill_price: Decimal,
+    #[wire = "ma"]
+    pub margin_asset: String,
+    #[wire = "N"]
+    pub commission_asset: Option<String>,
+    #[wire = "n"]
+    pub commission_amount: Option<Decimal>,
+    #[wire = "T"]
+    pub order_trade_time: u64,
+    #[wire = "t"]
+    pub order_trade_id: u64,
+    #[wire = "rp"]
+    pub trade_realized_profit: Decimal,
+    #[wire = "b"]
+    pub bid_quantity: Decimal,
+    #[wire = "a"]
+    pub ask_quantity: Decimal,
+    #[wire = "m"]
+    pub is_trade_maker: bool,
+    #[wire = "R"]
+    pub is_reduce_only: bool,
+    #[wire = "wt"]
+    pub stop_price_working_type: WorkingType,
+    #[wire = "ot"]
+    pub orig_order_type: OrderType,
+    #[wire = "ps"]
+    pub position_side: PositionSide,
+    #[wire = "cp"]
+    pub close_position: bool,
+    #[wire = "AP"]
+    pub activation_price: Option<Decimal>,
+    #[wire = "cr"]
+    pub callback_rate: Option<String>,
+    #[wire = "pP"]
+    pub price_protection: bool,
+    #[wire = "V"]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[wire = "pm"]
+    pub price_match: Option<PriceMatch>,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AccountConfigurationUpdateEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "T"]
+    pub transaction_time: u64,
+    #[wire = "i"]
+    pub account_alias: String,
+    #[wire = "ac"]
+    pub leverage: LeverageUpdate,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "e")]
+#[allow(clippy::large_enum_variant)]
+pub enum UserStreamEvent {
+    #[serde(rename = "MARGIN_CALL")]
+    MarginCall(MarginCallEvent),
+    #[serde(rename = "ACCOUNT_UPDATE")]
+    BalancePositionUpdate(BalancePositionUpdateEvent),
+    #[serde(rename = "ORDER_TRADE_UPDATE")]
+    OrderUpdate(OrderUpdateEvent),
+    #[serde(rename = "ACCOUNT_CONFIG_UPDATE")]
+    AccountConfigurationUpdate(AccountConfigurationUpdateEvent),
+    #[serde(rename = "listenKeyExpired")]
+    ListenKeyExpired(ListenKeyExpiredEvent),
+}
+
+impl StreamTopic<Coinm> for UserStream<'_> {
+    fn endpoint(&self) -> String {
+        format!("/ws/{}", self.listen_key)
+    }
+    type Event = UserStreamEvent;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::BinanceClient;
+    use futures_util::StreamExt;
+
+    #[tokio::test]
+    async fn test_agg_trade_stream() {
+        let client = BinanceClient::coinm();
+        let stream_topic = AggTradeStream {
+            symbol: "BTCUSD_PERP",
+        };
+        let response = client.connect_stream(&stream_topic).await.unwrap();
+
+        let mut stream = response.content;
+
+        for _ in 0..5 {
+            let event = stream.next().await.unwrap();
+            eprintln!("{:#?}", event);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pair_mark_price_stream() {
+        let client = BinanceClient::coinm();
+        let stream_topic = PairMarkPriceStream {
+            pair: "BTCUSD",
+            update_speed: PriceUpdateSpeed::Ms1000,
+        };
+        let response = client.connect_stream(&stream_topic).await.unwrap();
+
+        let mut stream = response.content;
+
+        for _ in 0..3 {
+            let event = stream.next().await.unwrap();
+            eprintln!("{:#?}", event);
+        }
+    }
+
+    #[test]
+    fn test_event_round_trip() {
+        use crate::response::tests::assert_round_trip;
+        assert_round_trip::<MarkPriceEvent>(
+            r#"{"e":"markPriceUpdate","E":1596095725000,"s":"BTCUSD_201225","p":"10934.62615417","P":"10962.17178236","i":"10933.62615417","r":"","T":1596096000000}"#,
+        );
+        assert_round_trip::<DepthEvent>(
+            r#"{"e":"depthUpdate","E":1591270260907,"T":1591270260891,"s":"BTCUSD_200626","ps":"BTCUSD","U":17285681,"u":17285702,"pu":17285675,"b":[["9517.6","10"]],"a":[["9518.5","45"]]}"#,
+        );
+        assert_round_trip::<BookTickerEvent>(
+            r#"{"e":"bookTicker","u":17242169,"s":"BTCUSD_200626","ps":"BTCUSD","b":"9548.1","B":"52","a":"9548.5","A":"11","T":1591268628155,"E":1591268628166}"#,
+        );
+        assert_round_trip::<UserStreamEvent>(
+            r#"{"e":"ORDER_TRADE_UPDATE","E":1591274595442,"T":1591274595453,"i":"SfsR","o":{"s":"BTCUSD_200925","c":"TEST","S":"SELL","o":"TRAILING_STOP_MARKET","f":"GTC","q":"2","p":"0","ap":"0","sp":"9103.1","x":"NEW","X":"NEW","i":8888888,"l":"0","z":"0","L":"0","ma":"BTC","N":"BTC","n":"0","T":1591274595442,"t":0,"rp":"0","b":"0","a":"0","m":false,"R":false,"wt":"CONTRACT_PRICE","ot":"TRAILING_STOP_MARKET","ps":"LONG","cp":false,"AP":"9476.8","cr":"5","pP":false,"V":"NONE","pm":"NONE"}}"#,
+        );
+        assert_round_trip::<UserStreamEvent>(
+            r#"{"e":"ACCOUNT_UPDATE","E":1564745798939,"T":1564745798938,"i":"SfsR","a":{"m":"ORDER","B":[{"a":"BTC","wb":"122624.12345678","cw":"100.12345678","bc":"50.12345678"}],"P":[{"s":"BTCUSD_200925","pa":"0","ep":"0","bep":"0","cr":"200","up":"0","mt":"isolated","iw":"0","ps":"BOTH"}]}}"#,
+        );
+    }
+}
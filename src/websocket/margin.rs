@@ -0,0 +1,108 @@
+use crate::{client::Margin, decimal::Decimal};
+
+use super::StreamTopic;
+use binance_async_api_macros::wire_names;
+use serde::{Deserialize, Serialize};
+
+pub use super::spot::{
+    AccountPositionEvent, BalancePosition, BalanceUpdateEvent, ListStatusEvent, ListStatusOrder,
+    ListenKeyExpiredEvent, OrderUpdateEvent,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct UserStream<'a> {
+    pub listen_key: &'a str,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MarginLevelStatusChangeEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "l"]
+    pub margin_level: String,
+    #[wire = "s"]
+    pub margin_level_status: String,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LiabilityChangeEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "a"]
+    pub asset: String,
+    #[wire = "t"]
+    pub liability_type: String,
+    #[wire = "T"]
+    pub transaction_id: u64,
+    #[wire = "p"]
+    pub principal: Decimal,
+    #[wire = "i"]
+    pub interest: Decimal,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "e")]
+#[allow(clippy::large_enum_variant)]
+pub enum UserStreamEvent {
+    #[serde(rename = "outboundAccountPosition")]
+    AccountPosition(AccountPositionEvent),
+    #[serde(rename = "balanceUpdate")]
+    BalanceUpdate(BalanceUpdateEvent),
+    #[serde(rename = "executionReport")]
+    OrderUpdate(OrderUpdateEvent),
+    #[serde(rename = "listStatus")]
+    ListStatus(ListStatusEvent),
+    #[serde(rename = "listenKeyExpired")]
+    ListenKeyExpired(ListenKeyExpiredEvent),
+    #[serde(rename = "MARGIN_LEVEL_STATUS_CHANGE")]
+    MarginLevelStatusChange(MarginLevelStatusChangeEvent),
+    #[serde(rename = "USER_LIABILITY_CHANGE")]
+    LiabilityChange(LiabilityChangeEvent),
+}
+
+impl StreamTopic<Margin> for UserStream<'_> {
+    fn endpoint(&self) -> String {
+        format!("/ws/{}", self.listen_key)
+    }
+    type Event = UserStreamEvent;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_stream_events() {
+        let parse = |json: &str| serde_json::from_str::<UserStreamEvent>(json).unwrap();
+        assert!(matches!(
+            parse(r#"{"e":"MARGIN_LEVEL_STATUS_CHANGE","E":1710993618000,"l":"1.18","s":"MARGIN_CALL"}"#),
+            UserStreamEvent::MarginLevelStatusChange(event) if event.margin_level_status == "MARGIN_CALL"
+        ));
+        assert!(matches!(
+            parse(r#"{"e":"USER_LIABILITY_CHANGE","E":1710993618000,"a":"BTC","t":"BORROW","T":1352286576452,"p":"1.0345343","i":"0"}"#),
+            UserStreamEvent::LiabilityChange(event) if event.transaction_id == 1352286576452
+        ));
+        assert!(matches!(
+            parse(
+                r#"{"e":"balanceUpdate","E":1573200697110,"a":"BTC","d":"100","T":1573200697068}"#
+            ),
+            UserStreamEvent::BalanceUpdate(_)
+        ));
+    }
+
+    #[test]
+    fn test_event_round_trip() {
+        use crate::response::tests::assert_round_trip;
+        assert_round_trip::<UserStreamEvent>(
+            r#"{"e":"MARGIN_LEVEL_STATUS_CHANGE","E":1710993618000,"l":"1.18","s":"MARGIN_CALL"}"#,
+        );
+        assert_round_trip::<UserStreamEvent>(
+            r#"{"e":"USER_LIABILITY_CHANGE","E":1710993618000,"a":"BTC","t":"BORROW","T":1352286576452,"p":"1.0345343","i":"0"}"#,
+        );
+        assert_round_trip::<UserStreamEvent>(
+            r#"{"e":"executionReport","E":1499405658658,"s":"ETHBTC","c":"mUvoqJxFIILMdfAW5iGSOW","S":"BUY","o":"LIMIT","f":"GTC","q":"1","p":"0.1026441","P":"0","F":"0","g":-1,"C":"","x":"NEW","X":"NEW","r":"NONE","i":4293153,"l":"0","z":"0","L":"0","n":"0","N":null,"T":1499405658657,"t":-1,"I":8641984,"w":true,"m":false,"M":false,"O":1499405658657,"Z":"0","Y":"0","Q":"0","W":1499405658657,"V":"NONE"}"#,
+        );
+    }
+}
@@ -1,25 +1,47 @@
 pub mod coinm;
+pub mod margin;
+pub mod options;
+pub mod portfolio_margin;
 pub mod spot;
 pub mod usdm;
 
-use crate::{client::BinanceClient, errors::WsConnectionError, response::Response};
-use futures_util::stream::{Stream, StreamExt};
-use serde::de::DeserializeOwned;
-use serde_json::{from_str, Value};
+use crate::{
+    client::{BinanceClient, StreamMarket},
+    errors::WsConnectionError,
+    json::parse_frame,
+    response::Response,
+};
+use futures_util::{
+    future::poll_fn,
+    stream::{Stream, StreamExt},
+};
+use serde::{de::DeserializeOwned, Deserialize};
 use std::{
     marker::PhantomData,
     pin::Pin,
-    str::FromStr,
     task::{Context, Poll},
 };
 use tokio::net::TcpStream;
 use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
 
-pub trait StreamTopic<T>: Clone + Copy {
+pub trait StreamTopic<T: StreamMarket>: Clone + Copy {
     fn endpoint(&self) -> String;
     type Event: DeserializeOwned + Clone;
 }
 
+// Events that can also be parsed as views into the frame they arrived in, see
+// `BinanceWebsocket::next_borrowed`. Numbers sent as strings stay `&str`.
+pub trait BorrowedEvent {
+    type Borrowed<'a>: Deserialize<'a>;
+}
+
+// Order book level sent as a [price, quantity] array
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct BookLevelRef<'a> {
+    pub price: &'a str,
+    pub qty: &'a str,
+}
+
 type WSStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
 
 #[derive(Debug)]
@@ -28,39 +50,43 @@ pub struct BinanceWebsocket<E> {
     _marker: PhantomData<E>,
 }
 
-impl<E: DeserializeOwned + Unpin> Stream for BinanceWebsocket<E> {
-    type Item = E;
-
-    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+impl<E> BinanceWebsocket<E> {
+    fn poll_text(&mut self, cx: &mut Context<'_>) -> Poll<Option<String>> {
         let msg = match self.stream.poll_next_unpin(cx) {
             Poll::Ready(Some(Ok(c))) => c,
             Poll::Ready(Some(Err(_))) | Poll::Ready(None) => return Poll::Ready(None),
             Poll::Pending => return Poll::Pending,
         };
-        let text = match msg {
-            Message::Text(msg) => msg,
+        match msg {
+            Message::Text(msg) => Poll::Ready(Some(msg)),
             Message::Binary(_) | Message::Frame(_) | Message::Pong(_) | Message::Ping(_) => {
                 cx.waker().wake_by_ref();
-                return Poll::Pending;
+                Poll::Pending
             }
-            Message::Close(_) => return Poll::Ready(None),
-        };
+            Message::Close(_) => Poll::Ready(None),
+        }
+    }
+}
 
-        let event = match from_str(&text) {
-            Ok(r) => r,
-            Err(e) => {
-                let val = Value::from_str(&text).unwrap();
-                eprintln!("Failed to parse event:");
-                eprintln!("{:#?}", val.as_object().unwrap());
-                panic!("parsing error: {}", e);
-            }
-        };
+impl<E: BorrowedEvent> BinanceWebsocket<E> {
+    // Parses the next event in place and hands it to `f`, which saves the
+    // allocations of the owned event. None once the connection is closed.
+    pub async fn next_borrowed<R>(&mut self, f: impl FnOnce(E::Borrowed<'_>) -> R) -> Option<R> {
+        let mut frame = poll_fn(|cx| self.poll_text(cx)).await?.into_bytes();
+        Some(f(parse_frame(&mut frame)))
+    }
+}
 
-        Poll::Ready(Some(event))
+impl<E: DeserializeOwned + Unpin> Stream for BinanceWebsocket<E> {
+    type Item = E;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_text(cx)
+            .map(|text| text.map(|text| parse_frame(&mut text.into_bytes())))
     }
 }
 
-impl<T> BinanceClient<T> {
+impl<T: StreamMarket> BinanceClient<T> {
     pub async fn connect_stream<S: StreamTopic<T>>(
         &self,
         topic: &S,
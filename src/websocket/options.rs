@@ -0,0 +1,207 @@
+use crate::{client::Options, decimal::Decimal};
+
+use super::StreamTopic;
+use binance_async_api_macros::wire_names;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy)]
+pub struct TickerStream<'a> {
+    pub symbol: &'a str,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TickerEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "T"]
+    pub transaction_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "o"]
+    pub open_price: Decimal,
+    #[wire = "h"]
+    pub high_price: Decimal,
+    #[wire = "l"]
+    pub low_price: Decimal,
+    #[wire = "c"]
+    pub last_price: Decimal,
+    #[wire = "V"]
+    pub volume: Decimal, // in contracts
+    #[wire = "A"]
+    pub amount: Decimal, // in quote asset
+    #[wire = "P"]
+    pub price_change_percent: String,
+    #[wire = "p"]
+    pub price_change: Decimal,
+    #[wire = "Q"]
+    pub last_qty: Decimal,
+    #[wire = "F"]
+    pub first_trade_id: i64,
+    #[wire = "L"]
+    pub last_trade_id: i64,
+    #[wire = "n"]
+    pub trade_count: u64,
+    #[wire = "bo"]
+    pub best_bid_price: Decimal,
+    #[wire = "ao"]
+    pub best_ask_price: Decimal,
+    #[wire = "bq"]
+    pub best_bid_qty: Decimal,
+    #[wire = "aq"]
+    pub best_ask_qty: Decimal,
+    #[wire = "b"]
+    pub bid_iv: String,
+    #[wire = "a"]
+    pub ask_iv: String,
+    #[wire = "d"]
+    pub delta: String,
+    #[wire = "t"]
+    pub theta: String,
+    #[wire = "g"]
+    pub gamma: String,
+    #[wire = "v"]
+    pub vega: String,
+    #[wire = "vo"]
+    pub mark_iv: String,
+    #[wire = "mp"]
+    pub mark_price: Decimal,
+    #[wire = "hl"]
+    pub high_price_limit: Decimal,
+    #[wire = "ll"]
+    pub low_price_limit: Decimal,
+    #[wire = "eep"]
+    pub estimated_exercise_price: Decimal,
+}
+
+impl StreamTopic<Options> for TickerStream<'_> {
+    fn endpoint(&self) -> String {
+        format!("/ws/{}@ticker", self.symbol)
+    }
+    type Event = TickerEvent;
+}
+
+// Every option of the underlying, e.g. ETH
+#[derive(Debug, Clone, Copy)]
+pub struct MarkPriceStream<'a> {
+    pub underlying_asset: &'a str,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MarkPriceEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "mp"]
+    pub mark_price: Decimal,
+}
+
+impl StreamTopic<Options> for MarkPriceStream<'_> {
+    fn endpoint(&self) -> String {
+        format!("/ws/{}@markPrice", self.underlying_asset)
+    }
+    type Event = Vec<MarkPriceEvent>;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct NewSymbolInfoStream;
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NewSymbolInfoEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    pub id: u64,
+    #[wire = "cid"]
+    pub contract_id: u64,
+    #[wire = "u"]
+    pub underlying: String,
+    #[wire = "qa"]
+    pub quote_asset: String,
+    #[wire = "s"]
+    pub symbol: String,
+    pub unit: u64,
+    #[wire = "mq"]
+    pub min_qty: Decimal,
+    #[wire = "d"]
+    pub side: String, // CALL or PUT
+    #[wire = "sp"]
+    pub strike_price: Decimal,
+    #[wire = "ed"]
+    pub expiry_date: u64,
+}
+
+impl StreamTopic<Options> for NewSymbolInfoStream {
+    fn endpoint(&self) -> String {
+        "/ws/option_pair".to_string()
+    }
+    type Event = NewSymbolInfoEvent;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpenInterestStream<'a> {
+    pub underlying_asset: &'a str,
+    pub expiration: &'a str, // YYMMDD
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OpenInterestEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "o"]
+    pub open_interest: Decimal, // in contracts
+    #[wire = "h"]
+    pub open_interest_usd: String,
+}
+
+impl StreamTopic<Options> for OpenInterestStream<'_> {
+    fn endpoint(&self) -> String {
+        format!(
+            "/ws/{}@openInterest@{}",
+            self.underlying_asset, self.expiration
+        )
+    }
+    type Event = Vec<OpenInterestEvent>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::BinanceClient;
+    use futures_util::StreamExt;
+
+    #[tokio::test]
+    async fn test_mark_price_stream() {
+        let client = BinanceClient::options();
+        let stream_topic = MarkPriceStream {
+            underlying_asset: "BTC",
+        };
+        let response = client.connect_stream(&stream_topic).await.unwrap();
+
+        let mut stream = response.content;
+
+        for _ in 0..3 {
+            let event = stream.next().await.unwrap();
+            eprintln!("{:#?}", event);
+        }
+    }
+
+    #[test]
+    fn test_event_round_trip() {
+        use crate::response::tests::assert_round_trip;
+        assert_round_trip::<TickerEvent>(
+            r#"{"e":"24hrTicker","E":1657706425200,"T":1657706425220,"s":"BTC-220930-18000-C","o":"2000","h":"2020","l":"2000","c":"2020","V":"1.42","A":"2841.2","P":"0.01","p":"20","Q":"0.01","F":27,"L":48,"n":20,"bo":"2012","ao":"2020","bq":"4.9","aq":"0.03","b":"0.1202","a":"0.1318","d":"0.98911","t":"-0.16961","g":"0.00004","v":"2.66584","vo":"0.10001","mp":"2003.5102","hl":"2023.511","ll":"1983.511","eep":"0"}"#,
+        );
+        assert_round_trip::<Vec<MarkPriceEvent>>(
+            r#"[{"e":"markPrice","E":1663684594227,"s":"ETH-220930-1500-C","mp":"30"}]"#,
+        );
+        assert_round_trip::<Vec<OpenInterestEvent>>(
+            r#"[{"e":"openInterest","E":1668759300045,"s":"ETH-221125-2700-C","o":"1580.87","h":"1912992.1781"}]"#,
+        );
+    }
+}
@@ -0,0 +1,347 @@
+use crate::{
+    client::PortfolioMargin,
+    decimal::Decimal,
+    enums::{
+        ExecutionType, OrderStatus, OrderType, PositionSide, PriceMatch, SelfTradePreventionMode,
+        Side, TimeInForce, WorkingType,
+    },
+};
+
+use super::StreamTopic;
+use binance_async_api_macros::wire_names;
+use serde::{Deserialize, Serialize};
+
+pub use super::{
+    margin::LiabilityChangeEvent,
+    spot::{
+        AccountPositionEvent, BalancePosition, BalanceUpdateEvent, ListenKeyExpiredEvent,
+        OrderUpdateEvent as MarginOrderUpdateEvent,
+    },
+    usdm::{BalanceUpdate, LeverageUpdate},
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct UserStream<'a> {
+    pub listen_key: &'a str,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FuturesOrderUpdateEvent {
+    #[wire = "fs"]
+    pub business_unit: String, // UM or CM
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "T"]
+    pub transaction_time: u64,
+    #[wire = "i"]
+    pub account_alias: String,
+    #[wire = "o"]
+    pub order_update: FuturesOrderUpdate,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FuturesOrderUpdate {
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "c"]
+    pub client_order_id: String,
+    #[wire = "S"]
+    pub side: Side,
+    #[wire = "o"]
+    pub order_type: OrderType,
+    #[wire = "f"]
+    pub time_in_force: TimeInForce,
+    #[wire = "q"]
+    pub orig_qty: Decimal,
+    #[wire = "p"]
+    pub orig_price: Decimal,
+    #[wire = "ap"]
+    pub average_price: Decimal,
+    #[wire = "sp"]
+    pub stop_price: Decimal,
+    #[wire = "x"]
+    pub current_order_execution_type: ExecutionType,
+    #[wire = "X"]
+    pub current_order_status: OrderStatus,
+    #[wire = "i"]
+    pub order_id: u64,
+    #[wire = "l"]
+    pub last_filled_qty: Decimal,
+    #[wire = "z"]
+    pub cummulative_filled_qty: Decimal,
+    #[wire = "L"]
+    pub last_fill_price: Decimal,
+    #[wire = "ma"]
+    pub margin_asset: Option<String>, // CM only
+    #[wire = "N"]
+    pub commission_asset: Option<String>,
+    #[wire = "n"]
+    pub commission_amount: Option<Decimal>,
+    #[wire = "T"]
+    pub order_trade_time: u64,
+    #[wire = "t"]
+    pub order_trade_id: u64,
+    #[wire = "b"]
+    pub bid_notional: Decimal,
+    #[wire = "a"]
+    pub ask_notional: Decimal,
+    #[wire = "m"]
+    pub is_trade_maker: bool,
+    #[wire = "R"]
+    pub is_reduce_only: bool,
+    #[wire = "ps"]
+    pub position_side: PositionSide,
+    #[wire = "rp"]
+    pub trade_realized_profit: Decimal,
+    #[wire = "st"]
+    pub strategy_type: Option<String>,
+    #[wire = "si"]
+    pub strategy_id: Option<u64>,
+    #[wire = "V"]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[wire = "pm"]
+    pub price_match: Option<PriceMatch>,
+    #[wire = "gtd"]
+    pub good_till_date: Option<u64>,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FuturesBalancePositionUpdateEvent {
+    #[wire = "fs"]
+    pub business_unit: String, // UM or CM
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "T"]
+    pub transaction_time: u64,
+    #[wire = "i"]
+    pub account_alias: String,
+    #[wire = "a"]
+    pub balance_position_update: FuturesBalancePositionUpdate,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FuturesBalancePositionUpdate {
+    #[wire = "m"]
+    pub reason: String,
+    #[wire = "B"]
+    pub balance_updates: Vec<BalanceUpdate>,
+    #[wire = "P"]
+    pub position_updates: Vec<FuturesPositionUpdate>,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FuturesPositionUpdate {
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "pa"]
+    pub position_amount: Decimal,
+    #[wire = "ep"]
+    pub entry_price: Decimal,
+    #[wire = "bep"]
+    pub breakeven_price: Option<Decimal>,
+    #[wire = "cr"]
+    pub realized_pnl: Decimal,
+    #[wire = "up"]
+    pub unrealized_pnl: Decimal,
+    #[wire = "ps"]
+    pub position_side: PositionSide,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FuturesAccountConfigurationUpdateEvent {
+    #[wire = "fs"]
+    pub business_unit: String, // UM or CM
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "T"]
+    pub transaction_time: u64,
+    #[wire = "ac"]
+    pub leverage: LeverageUpdate,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ConditionalOrderUpdateEvent {
+    #[wire = "fs"]
+    pub business_unit: String, // UM or CM
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "T"]
+    pub transaction_time: u64,
+    #[wire = "so"]
+    pub strategy_order: ConditionalOrderUpdate,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ConditionalOrderUpdate {
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "c"]
+    pub client_strategy_id: String,
+    #[wire = "si"]
+    pub strategy_id: u64,
+    #[wire = "S"]
+    pub side: Side,
+    #[wire = "st"]
+    pub strategy_type: String,
+    #[wire = "f"]
+    pub time_in_force: TimeInForce,
+    #[wire = "q"]
+    pub quantity: Decimal,
+    #[wire = "p"]
+    pub price: Decimal,
+    #[wire = "sp"]
+    pub stop_price: Decimal,
+    #[wire = "os"]
+    pub strategy_status: String,
+    #[wire = "T"]
+    pub book_time: u64,
+    #[wire = "ut"]
+    pub update_time: u64,
+    #[wire = "R"]
+    pub is_reduce_only: bool,
+    #[wire = "wt"]
+    pub stop_price_working_type: WorkingType,
+    #[wire = "ps"]
+    pub position_side: PositionSide,
+    #[wire = "cp"]
+    pub close_position: Option<bool>,
+    #[wire = "AP"]
+    pub activation_price: Option<Decimal>,
+    #[wire = "cr"]
+    pub callback_rate: Option<String>,
+    #[wire = "i"]
+    pub order_id: Option<u64>,
+    #[wire = "V"]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[wire = "gtd"]
+    pub good_till_date: Option<u64>,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RiskLevelChangeEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "u"]
+    pub uni_mmr: String,
+    #[wire = "s"]
+    pub status: String, // MARGIN_CALL, SUPPLY_MARGIN, REDUCE_ONLY, ACTIVE_LIQUIDATION or FORCE_LIQUIDATION
+    #[wire = "eq"]
+    pub account_equity: Decimal,
+    #[wire = "ae"]
+    pub actual_equity: Decimal,
+    #[wire = "m"]
+    pub maint_margin: Decimal,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OpenOrderLossEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "O"]
+    pub losses: Vec<OpenOrderLoss>,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OpenOrderLoss {
+    #[wire = "a"]
+    pub asset: String,
+    #[wire = "o"]
+    pub amount: Decimal,
+}
+
+// Futures events tell UM and CM apart with their `business_unit`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "e")]
+#[allow(clippy::large_enum_variant)]
+pub enum UserStreamEvent {
+    #[serde(rename = "ORDER_TRADE_UPDATE")]
+    FuturesOrderUpdate(FuturesOrderUpdateEvent),
+    #[serde(rename = "ACCOUNT_UPDATE")]
+    FuturesBalancePositionUpdate(FuturesBalancePositionUpdateEvent),
+    #[serde(rename = "ACCOUNT_CONFIG_UPDATE")]
+    FuturesAccountConfigurationUpdate(FuturesAccountConfigurationUpdateEvent),
+    #[serde(rename = "CONDITIONAL_ORDER_TRADE_UPDATE")]
+    ConditionalOrderUpdate(ConditionalOrderUpdateEvent),
+    #[serde(rename = "executionReport")]
+    MarginOrderUpdate(MarginOrderUpdateEvent),
+    #[serde(rename = "outboundAccountPosition")]
+    AccountPosition(AccountPositionEvent),
+    #[serde(rename = "balanceUpdate")]
+    BalanceUpdate(BalanceUpdateEvent),
+    #[serde(rename = "liabilityChange")]
+    LiabilityChange(LiabilityChangeEvent),
+    #[serde(rename = "riskLevelChange")]
+    RiskLevelChange(RiskLevelChangeEvent),
+    #[serde(rename = "openOrderLoss")]
+    OpenOrderLoss(OpenOrderLossEvent),
+    #[serde(rename = "listenKeyExpired")]
+    ListenKeyExpired(ListenKeyExpiredEvent),
+}
+
+impl StreamTopic<PortfolioMargin> for UserStream<'_> {
+    fn endpoint(&self) -> String {
+        format!("/ws/{}", self.listen_key)
+    }
+    type Event = UserStreamEvent;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UM_ORDER_UPDATE: &str = r#"{"e":"ORDER_TRADE_UPDATE","fs":"UM","E":1568879465651,"T":1568879465650,"i":"SfsR","o":{"s":"BTCUSDT","c":"TEST","S":"SELL","o":"TRAILING_STOP_MARKET","f":"GTC","q":"0.001","p":"0","ap":"0","sp":"7103.04","x":"NEW","X":"NEW","i":8886774,"l":"0","z":"0","L":"0","N":"USDT","n":"0","T":1568879465650,"t":0,"b":"0","a":"9.91","m":false,"R":false,"ps":"LONG","rp":"0","st":"C_TAKE_PROFIT","si":12893,"V":"EXPIRE_TAKER","pm":"NONE","gtd":0}}"#;
+    const CM_ORDER_UPDATE: &str = r#"{"e":"ORDER_TRADE_UPDATE","fs":"CM","E":1568879465651,"T":1568879465650,"i":"SfsR","o":{"s":"BTCUSD_PERP","c":"TEST","S":"BUY","o":"LIMIT","f":"GTC","q":"1","p":"60000.1","ap":"60000.1","sp":"0","x":"TRADE","X":"FILLED","i":8886775,"l":"1","z":"1","L":"60000.1","ma":"BTC","N":"BTC","n":"0.0000005","T":1568879465650,"t":1234,"b":"0","a":"0","m":true,"R":false,"ps":"BOTH","rp":"0"}}"#;
+    const RISK_LEVEL_CHANGE: &str = r#"{"e":"riskLevelChange","E":1587727187525,"u":"1.99999999","s":"MARGIN_CALL","eq":"30.23416728","ae":"30.23416728","m":"15.11708371"}"#;
+    const OPEN_ORDER_LOSS: &str = r#"{"e":"openOrderLoss","E":1678710578788,"O":[{"a":"BUSD","o":"-0.1232313"},{"a":"BNB","o":"-12.1232313"}]}"#;
+
+    #[test]
+    fn test_user_stream_events() {
+        let parse = |json: &str| serde_json::from_str::<UserStreamEvent>(json).unwrap();
+        let UserStreamEvent::FuturesOrderUpdate(um) = parse(UM_ORDER_UPDATE) else {
+            panic!("not an order update");
+        };
+        assert_eq!(um.business_unit, "UM");
+        assert_eq!(um.order_update.order_type, OrderType::TrailingStopMarket);
+        assert_eq!(um.order_update.margin_asset, None);
+        assert_eq!(um.order_update.strategy_id, Some(12893));
+        let UserStreamEvent::FuturesOrderUpdate(cm) = parse(CM_ORDER_UPDATE) else {
+            panic!("not an order update");
+        };
+        assert_eq!(cm.business_unit, "CM");
+        assert_eq!(cm.order_update.current_order_status, OrderStatus::Filled);
+        assert_eq!(cm.order_update.margin_asset.as_deref(), Some("BTC"));
+        assert_eq!(cm.order_update.self_trade_prevention_mode, None);
+        assert!(matches!(
+            parse(RISK_LEVEL_CHANGE),
+            UserStreamEvent::RiskLevelChange(event) if event.status == "MARGIN_CALL"
+        ));
+        assert!(matches!(
+            parse(OPEN_ORDER_LOSS),
+            UserStreamEvent::OpenOrderLoss(event) if event.losses.len() == 2
+        ));
+    }
+
+    #[test]
+    fn test_event_round_trip() {
+        use crate::response::tests::assert_round_trip;
+        for fixture in [
+            UM_ORDER_UPDATE,
+            CM_ORDER_UPDATE,
+            RISK_LEVEL_CHANGE,
+            OPEN_ORDER_LOSS,
+        ] {
+            assert_round_trip::<UserStreamEvent>(fixture);
+        }
+    }
+}
@@ -0,0 +1,848 @@
+use crate::{
+    client::Spot,
+    decimal::Decimal,
+    enums::{ExecutionType, OrderStatus, OrderType, SelfTradePreventionMode, Side, TimeInForce},
+};
+
+use super::{BookLevelRef, BorrowedEvent, StreamTopic};
+use binance_async_api_macros::wire_names;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy)]
+pub struct TradeStream<'a> {
+    pub symbol: &'a str,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TradeEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "t"]
+    pub id: u64,
+    #[wire = "p"]
+    pub price: Decimal,
+    #[wire = "q"]
+    pub qty: Decimal,
+    #[wire = "T"]
+    pub trade_time: u64,
+    #[wire = "m"]
+    pub buyer_is_maker: bool,
+}
+
+impl StreamTopic<Spot> for TradeStream<'_> {
+    fn endpoint(&self) -> String {
+        format!("/ws/{}@trade", self.symbol.to_lowercase())
+    }
+    type Event = TradeEvent;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AggTradeStream<'a> {
+    pub symbol: &'a str,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AggTradeEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "a"]
+    pub id: u64,
+    #[wire = "p"]
+    pub price: Decimal,
+    #[wire = "q"]
+    pub qty: Decimal,
+    #[wire = "f"]
+    pub first_trade_id: u64,
+    #[wire = "l"]
+    pub last_trade_id: u64,
+    #[wire = "T"]
+    pub trade_time: u64,
+    #[wire = "m"]
+    pub buyer_is_maker: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AggTradeEventRef<'a> {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: &'a str,
+    #[serde(rename = "a")]
+    pub id: u64,
+    #[serde(rename = "p")]
+    pub price: &'a str,
+    #[serde(rename = "q")]
+    pub qty: &'a str,
+    #[serde(rename = "f")]
+    pub first_trade_id: u64,
+    #[serde(rename = "l")]
+    pub last_trade_id: u64,
+    #[serde(rename = "T")]
+    pub trade_time: u64,
+    #[serde(rename = "m")]
+    pub buyer_is_maker: bool,
+}
+
+impl BorrowedEvent for AggTradeEvent {
+    type Borrowed<'a> = AggTradeEventRef<'a>;
+}
+
+impl StreamTopic<Spot> for AggTradeStream<'_> {
+    fn endpoint(&self) -> String {
+        format!("/ws/{}@aggTrade", self.symbol.to_lowercase())
+    }
+    type Event = AggTradeEvent;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct KlineStream<'a> {
+    pub symbol: &'a str,
+    pub interval: &'a str,
+    pub utc_offset: Option<&'a str>, // e.g. "+08:00", defaults to UTC
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct KlineEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "k"]
+    pub kline: Kline,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Kline {
+    #[wire = "t"]
+    pub open_time: u64,
+    #[wire = "T"]
+    pub close_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "i"]
+    pub interval: String,
+    #[wire = "f"]
+    pub first_trade_id: i64,
+    #[wire = "L"]
+    pub last_trade_id: i64,
+    #[wire = "o"]
+    pub open_price: Decimal,
+    #[wire = "c"]
+    pub close_price: Decimal,
+    #[wire = "h"]
+    pub high_price: Decimal,
+    #[wire = "l"]
+    pub low_price: Decimal,
+    #[wire = "v"]
+    pub base_asset_volume: Decimal,
+    #[wire = "n"]
+    pub trade_count: u64,
+    #[wire = "x"]
+    pub is_closed: bool,
+    #[wire = "q"]
+    pub quote_asset_volume: Decimal,
+    #[wire = "V"]
+    pub taker_buy_base_asset_volume: Decimal,
+    #[wire = "Q"]
+    pub taker_buy_quote_asset_volume: Decimal,
+}
+
+impl StreamTopic<Spot> for KlineStream<'_> {
+    fn endpoint(&self) -> String {
+        match self.utc_offset {
+            Some(offset) => format!(
+                "/ws/{}@kline_{}@{}",
+                self.symbol.to_lowercase(),
+                self.interval,
+                offset
+            ),
+            None => format!("/ws/{}@kline_{}", self.symbol.to_lowercase(), self.interval),
+        }
+    }
+    type Event = KlineEvent;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MiniTickerStream<'a> {
+    pub symbol: &'a str,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AllMiniTickersStream;
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MiniTickerEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "c"]
+    pub close_price: Decimal,
+    #[wire = "o"]
+    pub open_price: Decimal,
+    #[wire = "h"]
+    pub high_price: Decimal,
+    #[wire = "l"]
+    pub low_price: Decimal,
+    #[wire = "v"]
+    pub base_asset_volume: Decimal,
+    #[wire = "q"]
+    pub quote_asset_volume: Decimal,
+}
+
+impl StreamTopic<Spot> for MiniTickerStream<'_> {
+    fn endpoint(&self) -> String {
+        format!("/ws/{}@miniTicker", self.symbol.to_lowercase())
+    }
+    type Event = MiniTickerEvent;
+}
+
+impl StreamTopic<Spot> for AllMiniTickersStream {
+    fn endpoint(&self) -> String {
+        "/ws/!miniTicker@arr".to_string()
+    }
+    type Event = Vec<MiniTickerEvent>;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TickerStream<'a> {
+    pub symbol: &'a str,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AllTickersStream;
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TickerEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "p"]
+    pub price_change: Decimal,
+    #[wire = "P"]
+    pub price_change_percent: String,
+    #[wire = "w"]
+    pub weighted_avg_price: Decimal,
+    #[wire = "x"]
+    pub prev_close_price: Decimal,
+    #[wire = "c"]
+    pub last_price: Decimal,
+    #[wire = "Q"]
+    pub last_qty: Decimal,
+    #[wire = "b"]
+    pub best_bid_price: Decimal,
+    #[wire = "B"]
+    pub best_bid_qty: Decimal,
+    #[wire = "a"]
+    pub best_ask_price: Decimal,
+    #[wire = "A"]
+    pub best_ask_qty: Decimal,
+    #[wire = "o"]
+    pub open_price: Decimal,
+    #[wire = "h"]
+    pub high_price: Decimal,
+    #[wire = "l"]
+    pub low_price: Decimal,
+    #[wire = "v"]
+    pub base_asset_volume: Decimal,
+    #[wire = "q"]
+    pub quote_asset_volume: Decimal,
+    #[wire = "O"]
+    pub open_time: u64,
+    #[wire = "C"]
+    pub close_time: u64,
+    #[wire = "F"]
+    pub first_trade_id: i64,
+    #[wire = "L"]
+    pub last_trade_id: i64,
+    #[wire = "n"]
+    pub trade_count: u64,
+}
+
+impl StreamTopic<Spot> for TickerStream<'_> {
+    fn endpoint(&self) -> String {
+        format!("/ws/{}@ticker", self.symbol.to_lowercase())
+    }
+    type Event = TickerEvent;
+}
+
+impl StreamTopic<Spot> for AllTickersStream {
+    fn endpoint(&self) -> String {
+        "/ws/!ticker@arr".to_string()
+    }
+    type Event = Vec<TickerEvent>;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum RollingWindowSize {
+    OneHour,
+    FourHours,
+    OneDay,
+}
+
+impl RollingWindowSize {
+    fn as_str(&self) -> &'static str {
+        match self {
+            RollingWindowSize::OneHour => "1h",
+            RollingWindowSize::FourHours => "4h",
+            RollingWindowSize::OneDay => "1d",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RollingWindowTickerStream<'a> {
+    pub symbol: &'a str,
+    pub window_size: RollingWindowSize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AllRollingWindowTickersStream {
+    pub window_size: RollingWindowSize,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RollingWindowTickerEvent {
+    #[wire = "e"]
+    pub event_type: String, // "1hTicker", "4hTicker" or "1dTicker"
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "p"]
+    pub price_change: Decimal,
+    #[wire = "P"]
+    pub price_change_percent: String,
+    #[wire = "o"]
+    pub open_price: Decimal,
+    #[wire = "h"]
+    pub high_price: Decimal,
+    #[wire = "l"]
+    pub low_price: Decimal,
+    #[wire = "c"]
+    pub last_price: Decimal,
+    #[wire = "w"]
+    pub weighted_avg_price: Decimal,
+    #[wire = "v"]
+    pub base_asset_volume: Decimal,
+    #[wire = "q"]
+    pub quote_asset_volume: Decimal,
+    #[wire = "O"]
+    pub open_time: u64,
+    #[wire = "C"]
+    pub close_time: u64,
+    #[wire = "F"]
+    pub first_trade_id: i64,
+    #[wire = "L"]
+    pub last_trade_id: i64,
+    #[wire = "n"]
+    pub trade_count: u64,
+}
+
+impl StreamTopic<Spot> for RollingWindowTickerStream<'_> {
+    fn endpoint(&self) -> String {
+        format!(
+            "/ws/{}@ticker_{}",
+            self.symbol.to_lowercase(),
+            self.window_size.as_str()
+        )
+    }
+    type Event = RollingWindowTickerEvent;
+}
+
+impl StreamTopic<Spot> for AllRollingWindowTickersStream {
+    fn endpoint(&self) -> String {
+        format!("/ws/!ticker_{}@arr", self.window_size.as_str())
+    }
+    type Event = Vec<RollingWindowTickerEvent>;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BookTickerStream<'a> {
+    pub symbol: &'a str,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BookTickerEvent {
+    #[wire = "u"]
+    pub order_book_update_id: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "b"]
+    pub best_bid_price: Decimal,
+    #[wire = "B"]
+    pub best_bid_qty: Decimal,
+    #[wire = "a"]
+    pub best_ask_price: Decimal,
+    #[wire = "A"]
+    pub best_ask_qty: Decimal,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BookTickerEventRef<'a> {
+    #[serde(rename = "u")]
+    pub order_book_update_id: u64,
+    #[serde(rename = "s")]
+    pub symbol: &'a str,
+    #[serde(rename = "b")]
+    pub best_bid_price: &'a str,
+    #[serde(rename = "B")]
+    pub best_bid_qty: &'a str,
+    #[serde(rename = "a")]
+    pub best_ask_price: &'a str,
+    #[serde(rename = "A")]
+    pub best_ask_qty: &'a str,
+}
+
+impl BorrowedEvent for BookTickerEvent {
+    type Borrowed<'a> = BookTickerEventRef<'a>;
+}
+
+impl StreamTopic<Spot> for BookTickerStream<'_> {
+    fn endpoint(&self) -> String {
+        format!("/ws/{}@bookTicker", self.symbol.to_lowercase())
+    }
+    type Event = BookTickerEvent;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AvgPriceStream<'a> {
+    pub symbol: &'a str,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AvgPriceEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "i"]
+    pub interval: String,
+    #[wire = "w"]
+    pub avg_price: Decimal,
+    #[wire = "T"]
+    pub last_trade_time: u64,
+}
+
+impl StreamTopic<Spot> for AvgPriceStream<'_> {
+    fn endpoint(&self) -> String {
+        format!("/ws/{}@avgPrice", self.symbol.to_lowercase())
+    }
+    type Event = AvgPriceEvent;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum DepthUpdateSpeed {
+    Ms100,
+    Ms1000,
+}
+
+impl DepthUpdateSpeed {
+    fn suffix(&self) -> &'static str {
+        match self {
+            DepthUpdateSpeed::Ms100 => "@100ms",
+            DepthUpdateSpeed::Ms1000 => "",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum PartialDepthLevels {
+    Five = 5,
+    Ten = 10,
+    Twenty = 20,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PartialDepthStream<'a> {
+    pub symbol: &'a str,
+    pub levels: PartialDepthLevels,
+    pub update_speed: DepthUpdateSpeed,
+}
+
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PartialDepthEvent {
+    pub last_update_id: u64,
+    pub bids: Vec<BookLevel>,
+    pub asks: Vec<BookLevel>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartialDepthEventRef<'a> {
+    pub last_update_id: u64,
+    #[serde(borrow)]
+    pub bids: Vec<BookLevelRef<'a>>,
+    #[serde(borrow)]
+    pub asks: Vec<BookLevelRef<'a>>,
+}
+
+impl BorrowedEvent for PartialDepthEvent {
+    type Borrowed<'a> = PartialDepthEventRef<'a>;
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BookLevel {
+    pub price: Decimal,
+    pub qty: Decimal,
+}
+
+serialize_as_tuple!(BookLevel { price, qty });
+
+impl StreamTopic<Spot> for PartialDepthStream<'_> {
+    fn endpoint(&self) -> String {
+        format!(
+            "/ws/{}@depth{}{}",
+            self.symbol.to_lowercase(),
+            self.levels as u64,
+            self.update_speed.suffix()
+        )
+    }
+    type Event = PartialDepthEvent;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DiffDepthStream<'a> {
+    pub symbol: &'a str,
+    pub update_speed: DepthUpdateSpeed,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DiffDepthEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "U"]
+    pub first_update_id: u64,
+    #[wire = "u"]
+    pub final_update_id: u64,
+    #[wire = "b"]
+    pub bid_updates: Vec<BookLevel>,
+    #[wire = "a"]
+    pub ask_updates: Vec<BookLevel>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DiffDepthEventRef<'a> {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: &'a str,
+    #[serde(rename = "U")]
+    pub first_update_id: u64,
+    #[serde(rename = "u")]
+    pub final_update_id: u64,
+    #[serde(rename = "b", borrow)]
+    pub bid_updates: Vec<BookLevelRef<'a>>,
+    #[serde(rename = "a", borrow)]
+    pub ask_updates: Vec<BookLevelRef<'a>>,
+}
+
+impl BorrowedEvent for DiffDepthEvent {
+    type Borrowed<'a> = DiffDepthEventRef<'a>;
+}
+
+impl StreamTopic<Spot> for DiffDepthStream<'_> {
+    fn endpoint(&self) -> String {
+        format!(
+            "/ws/{}@depth{}",
+            self.symbol.to_lowercase(),
+            self.update_speed.suffix()
+        )
+    }
+    type Event = DiffDepthEvent;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct UserStream<'a> {
+    pub listen_key: &'a str,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AccountPositionEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "u"]
+    pub last_update_time: u64,
+    #[wire = "B"]
+    pub balances: Vec<BalancePosition>,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BalancePosition {
+    #[wire = "a"]
+    pub asset: String,
+    #[wire = "f"]
+    pub free: Decimal,
+    #[wire = "l"]
+    pub locked: Decimal,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BalanceUpdateEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "a"]
+    pub asset: String,
+    #[wire = "d"]
+    pub balance_delta: Decimal,
+    #[wire = "T"]
+    pub clear_time: u64,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrderUpdateEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "c"]
+    pub client_order_id: String,
+    #[wire = "S"]
+    pub side: Side,
+    #[wire = "o"]
+    pub order_type: OrderType,
+    #[wire = "f"]
+    pub time_in_force: TimeInForce,
+    #[wire = "q"]
+    pub orig_qty: Decimal,
+    #[wire = "p"]
+    pub orig_price: Decimal,
+    #[wire = "P"]
+    pub stop_price: Decimal,
+    #[wire = "F"]
+    pub iceberg_qty: Decimal,
+    #[wire = "g"]
+    pub order_list_id: i64, // -1 if the order is not part of a list
+    #[wire = "C"]
+    pub orig_client_order_id: String, // empty unless the order was canceled
+    #[wire = "x"]
+    pub current_order_execution_type: ExecutionType,
+    #[wire = "X"]
+    pub current_order_status: OrderStatus,
+    #[wire = "r"]
+    pub reject_reason: String,
+    #[wire = "i"]
+    pub order_id: u64,
+    #[wire = "l"]
+    pub last_filled_qty: Decimal,
+    #[wire = "z"]
+    pub cummulative_filled_qty: Decimal,
+    #[wire = "L"]
+    pub last_fill_price: Decimal,
+    #[wire = "n"]
+    pub commission_amount: Decimal,
+    #[wire = "N"]
+    pub commission_asset: Option<String>,
+    #[wire = "T"]
+    pub transaction_time: u64,
+    #[wire = "t"]
+    pub trade_id: i64, // -1 unless the execution type is TRADE
+    #[wire = "w"]
+    pub is_on_book: bool,
+    #[wire = "m"]
+    pub is_trade_maker: bool,
+    #[wire = "O"]
+    pub order_creation_time: u64,
+    #[wire = "Z"]
+    pub cummulative_quote_qty: Decimal,
+    #[wire = "Y"]
+    pub last_quote_qty: Decimal,
+    #[wire = "Q"]
+    pub quote_order_qty: Decimal,
+    #[wire = "W"]
+    pub working_time: Option<u64>,
+    #[wire = "V"]
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+    #[wire = "d"]
+    pub trailing_delta: Option<u64>,
+    #[wire = "D"]
+    pub trailing_time: Option<u64>,
+    #[wire = "j"]
+    pub strategy_id: Option<u64>,
+    #[wire = "J"]
+    pub strategy_type: Option<u64>,
+    #[wire = "v"]
+    pub prevented_match_id: Option<u64>,
+    #[wire = "A"]
+    pub prevented_qty: Option<Decimal>,
+    #[wire = "B"]
+    pub last_prevented_qty: Option<Decimal>,
+    #[wire = "u"]
+    pub trade_group_id: Option<u64>,
+    #[wire = "U"]
+    pub counter_order_id: Option<u64>,
+    #[wire = "Cs"]
+    pub counter_symbol: Option<String>,
+    #[wire = "pl"]
+    pub prevented_execution_qty: Option<Decimal>,
+    #[wire = "pL"]
+    pub prevented_execution_price: Option<Decimal>,
+    #[wire = "pY"]
+    pub prevented_execution_quote_qty: Option<Decimal>,
+    #[wire = "b"]
+    pub match_type: Option<String>,
+    #[wire = "a"]
+    pub allocation_id: Option<u64>,
+    #[wire = "k"]
+    pub working_floor: Option<String>,
+    #[wire = "uS"]
+    pub used_sor: Option<bool>,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ListStatusEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "g"]
+    pub order_list_id: u64,
+    #[wire = "c"]
+    pub contingency_type: String,
+    #[wire = "l"]
+    pub list_status_type: String,
+    #[wire = "L"]
+    pub list_order_status: String,
+    #[wire = "r"]
+    pub list_reject_reason: String,
+    #[wire = "C"]
+    pub list_client_order_id: String,
+    #[wire = "T"]
+    pub transaction_time: u64,
+    #[wire = "O"]
+    pub orders: Vec<ListStatusOrder>,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ListStatusOrder {
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "i"]
+    pub order_id: u64,
+    #[wire = "c"]
+    pub client_order_id: String,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ListenKeyExpiredEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "listenKey"]
+    pub listen_key: String,
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ExternalLockUpdateEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "a"]
+    pub asset: String,
+    #[wire = "d"]
+    pub locked_delta: Decimal,
+    #[wire = "T"]
+    pub transaction_time: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "e")]
+#[allow(clippy::large_enum_variant)]
+pub enum UserStreamEvent {
+    #[serde(rename = "outboundAccountPosition")]
+    AccountPosition(AccountPositionEvent),
+    #[serde(rename = "balanceUpdate")]
+    BalanceUpdate(BalanceUpdateEvent),
+    #[serde(rename = "executionReport")]
+    OrderUpdate(OrderUpdateEvent),
+    #[serde(rename = "listStatus")]
+    ListStatus(ListStatusEvent),
+    #[serde(rename = "listenKeyExpired")]
+    ListenKeyExpired(ListenKeyExpiredEvent),
+    #[serde(rename = "externalLockUpdate")]
+    ExternalLockUpdate(ExternalLockUpdateEvent),
+}
+
+impl StreamTopic<Spot> for UserStream<'_> {
+    fn endpoint(&self) -> String {
+        format!("/ws/{}", self.listen_key)
+    }
+    type Event = UserStreamEvent;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::BinanceClient;
+    use futures_util::StreamExt;
+
+    #[tokio::test]
+    async fn test_trade_stream() {
+        let client = BinanceClient::spot();
+        let stream_topic = TradeStream { symbol: "BTCUSDT" };
+        let response = client.connect_stream(&stream_topic).await.unwrap();
+
+        let mut stream = response.content;
+
+        for _ in 0..5 {
+            let event = stream.next().await.unwrap();
+            eprintln!("{:#?}", event);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_partial_depth_stream() {
+        let client = BinanceClient::spot();
+        let stream_topic = PartialDepthStream {
+            symbol: "BTCUSDT",
+            levels: PartialDepthLevels::Five,
+            update_speed: DepthUpdateSpeed::Ms100,
+        };
+        let response = client.connect_stream(&stream_topic).await.unwrap();
+
+        let mut stream = response.content;
+
+        for _ in 0..5 {
+            let event = stream.next().await.unwrap();
+            eprintln!("{:#?}", event);
+        }
+    }
+
+    #[test]
+    fn test_event_round_trip() {
+        use crate::response::tests::assert_round_trip;
+        assert_round_trip::<TradeEvent>(
+            r#"{"e":"trade","E":1672515782136,"s":"BNBBTC","t":12345,"p":"0.001","q":"100","T":1672515782136,"m":true,"M":true}"#,
+        );
+        assert_round_trip::<DiffDepthEvent>(
+            r#"{"e":"depthUpdate","E":1672515782136,"s":"BNBBTC","U":157,"u":160,"b":[["0.0024","10"]],"a":[["0.0026","100"]]}"#,
+        );
+        assert_round_trip::<UserStreamEvent>(
+            r#"{"e":"executionReport","E":1499405658658,"s":"ETHBTC","c":"mUvoqJxFIILMdfAW5iGSOW","S":"BUY","o":"LIMIT","f":"GTC","q":"1","p":"0.1026441","P":"0","F":"0","g":-1,"C":"","x":"NEW","X":"NEW","r":"NONE","i":4293153,"l":"0","z":"0","L":"0","n":"0","N":null,"T":1499405658657,"t":-1,"I":8641984,"w":true,"m":false,"M":false,"O":1499405658657,"Z":"0","Y":"0","Q":"0","W":1499405658657,"V":"NONE"}"#,
+        );
+    }
+}
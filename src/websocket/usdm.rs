@@ -1,25 +1,56 @@
-use crate::client::Usdm;
-
-use super::StreamTopic;
-use serde::Deserialize;
+use crate::{
+    client::Usdm,
+    decimal::Decimal,
+    enums::{
+        ExecutionType, OrderStatus, OrderType, PositionSide, SelfTradePreventionMode, Side,
+        TimeInForce, WorkingType,
+    },
+};
+
+use super::{BookLevelRef, BorrowedEvent, StreamTopic};
+use binance_async_api_macros::wire_names;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy)]
 pub struct AggTradeStream<'a> {
     pub symbol: &'a str,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct AggTradeEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "a"]
+    pub id: u64,
+    #[wire = "p"]
+    pub price: Decimal,
+    #[wire = "q"]
+    pub qty: Decimal,
+    #[wire = "f"]
+    pub first_trade_id: u64,
+    #[wire = "l"]
+    pub last_trade_id: u64,
+    #[wire = "T"]
+    pub trade_time: u64,
+    #[wire = "m"]
+    pub buyer_is_maker: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AggTradeEventRef<'a> {
     #[serde(rename = "E")]
     pub event_time: u64,
     #[serde(rename = "s")]
-    pub symbol: String,
+    pub symbol: &'a str,
     #[serde(rename = "a")]
     pub id: u64,
     #[serde(rename = "p")]
-    pub price: String,
+    pub price: &'a str,
     #[serde(rename = "q")]
-    pub qty: String,
+    pub qty: &'a str,
     #[serde(rename = "f")]
     pub first_trade_id: u64,
     #[serde(rename = "l")]
@@ -30,6 +61,10 @@ pub struct AggTradeEvent {
     pub buyer_is_maker: bool,
 }
 
+impl BorrowedEvent for AggTradeEvent {
+    type Borrowed<'a> = AggTradeEventRef<'a>;
+}
+
 impl StreamTopic<Usdm> for AggTradeStream<'_> {
     fn endpoint(&self) -> String {
         format!("/ws/{}@aggTrade", self.symbol.to_lowercase())
@@ -42,8 +77,29 @@ pub struct BookTickerStream<'a> {
     pub symbol: &'a str,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BookTickerEvent {
+    #[wire = "u"]
+    pub order_book_update_id: u64,
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "T"]
+    pub transaction_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "b"]
+    pub best_bid_price: Decimal,
+    #[wire = "B"]
+    pub best_bid_qty: Decimal,
+    #[wire = "a"]
+    pub best_ask_price: Decimal,
+    #[wire = "A"]
+    pub best_ask_qty: Decimal,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BookTickerEventRef<'a> {
     #[serde(rename = "u")]
     pub order_book_update_id: u64,
     #[serde(rename = "E")]
@@ -51,15 +107,19 @@ pub struct BookTickerEvent {
     #[serde(rename = "T")]
     pub transaction_time: u64,
     #[serde(rename = "s")]
-    pub symbol: String,
+    pub symbol: &'a str,
     #[serde(rename = "b")]
-    pub best_bid_price: String,
+    pub best_bid_price: &'a str,
     #[serde(rename = "B")]
-    pub best_bid_qty: String,
+    pub best_bid_qty: &'a str,
     #[serde(rename = "a")]
-    pub best_ask_price: String,
+    pub best_ask_price: &'a str,
     #[serde(rename = "A")]
-    pub best_ask_qty: String,
+    pub best_ask_qty: &'a str,
+}
+
+impl BorrowedEvent for BookTickerEvent {
+    type Borrowed<'a> = BookTickerEventRef<'a>;
 }
 
 impl StreamTopic<Usdm> for BookTickerStream<'_> {
@@ -69,32 +129,59 @@ impl StreamTopic<Usdm> for BookTickerStream<'_> {
     type Event = BookTickerEvent;
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DiffDepthEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "T"]
+    pub transaction_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "U"]
+    pub first_update_id: u64,
+    #[wire = "u"]
+    pub final_update_id: u64,
+    #[wire = "pu"]
+    pub last_event_final_update_id: u64,
+    #[wire = "b"]
+    pub bid_updates: Vec<BookLevelUpdate>,
+    #[wire = "a"]
+    pub ask_updates: Vec<BookLevelUpdate>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DiffDepthEventRef<'a> {
     #[serde(rename = "E")]
     pub event_time: u64,
     #[serde(rename = "T")]
     pub transaction_time: u64,
     #[serde(rename = "s")]
-    pub symbol: String,
+    pub symbol: &'a str,
     #[serde(rename = "U")]
     pub first_update_id: u64,
     #[serde(rename = "u")]
     pub final_update_id: u64,
     #[serde(rename = "pu")]
     pub last_event_final_update_id: u64,
-    #[serde(rename = "b")]
-    pub bid_updates: Vec<BookLevelUpdate>,
-    #[serde(rename = "a")]
-    pub ask_updates: Vec<BookLevelUpdate>,
+    #[serde(rename = "b", borrow)]
+    pub bid_updates: Vec<BookLevelRef<'a>>,
+    #[serde(rename = "a", borrow)]
+    pub ask_updates: Vec<BookLevelRef<'a>>,
+}
+
+impl BorrowedEvent for DiffDepthEvent {
+    type Borrowed<'a> = DiffDepthEventRef<'a>;
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct BookLevelUpdate {
-    pub price: String,
-    pub qty: String,
+    pub price: Decimal,
+    pub qty: Decimal,
 }
 
+serialize_as_tuple!(BookLevelUpdate { price, qty });
+
 #[derive(Debug, Clone, Copy)]
 pub struct DiffDepthStream<'a> {
     pub symbol: &'a str,
@@ -107,228 +194,292 @@ impl StreamTopic<Usdm> for DiffDepthStream<'_> {
     type Event = DiffDepthEvent;
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct ContractInfoStream;
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ContractInfoEvent {
+    #[wire = "E"]
+    pub event_time: u64,
+    #[wire = "s"]
+    pub symbol: String,
+    #[wire = "ps"]
+    pub pair: String,
+    #[wire = "ct"]
+    pub contract_type: String,
+    #[wire = "dt"]
+    pub delivery_date: u64,
+    #[wire = "ot"]
+    pub onboard_date: u64,
+    #[wire = "cs"]
+    pub contract_status: String,
+    #[wire = "bks"]
+    pub brackets: Option<Vec<ContractBracket>>, // only sent when brackets change
+}
+
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ContractBracket {
+    #[wire = "bs"]
+    pub bracket: u64,
+    #[wire = "bnf"]
+    pub floor_notional: f64,
+    #[wire = "bnc"]
+    pub cap_notional: f64,
+    #[wire = "mmr"]
+    pub maint_margin_ratio: f64,
+    #[wire = "cf"]
+    pub auxiliary_number: f64,
+    #[wire = "mi"]
+    pub min_leverage: u64,
+    #[wire = "ma"]
+    pub max_leverage: u64,
+}
+
+impl StreamTopic<Usdm> for ContractInfoStream {
+    fn endpoint(&self) -> String {
+        "/ws/!contractInfo".to_string()
+    }
+    type Event = ContractInfoEvent;
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct UserStream<'a> {
     pub listen_key: &'a str,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PositionMarginCall {
-    #[serde(rename = "s")]
+    #[wire = "s"]
     pub symbol: String,
-    #[serde(rename = "ps")]
-    pub position_side: String,
-    #[serde(rename = "pa")]
-    pub position_amount: String,
-    #[serde(rename = "mt")]
+    #[wire = "ps"]
+    pub position_side: PositionSide,
+    #[wire = "pa"]
+    pub position_amount: Decimal,
+    #[wire = "mt"]
     pub margin_type: String,
-    #[serde(rename = "iw")]
-    pub isolated_wallet: Option<String>,
-    #[serde(rename = "mp")]
-    pub mark_price: String,
-    #[serde(rename = "up")]
-    pub unrealized_pnl: String,
-    #[serde(rename = "mm")]
-    pub required_maintenance_margin: String,
+    #[wire = "iw"]
+    pub isolated_wallet: Option<Decimal>,
+    #[wire = "mp"]
+    pub mark_price: Decimal,
+    #[wire = "up"]
+    pub unrealized_pnl: Decimal,
+    #[wire = "mm"]
+    pub required_maintenance_margin: Decimal,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct MarginCallEvent {
-    #[serde(rename = "E")]
+    #[wire = "E"]
     pub event_time: u64,
-    #[serde(rename = "cw")]
-    pub cross_wallet_balance: Option<String>,
-    #[serde(rename = "p")]
+    #[wire = "cw"]
+    pub cross_wallet_balance: Option<Decimal>,
+    #[wire = "p"]
     pub positions: Vec<PositionMarginCall>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BalancePositionUpdateEvent {
-    #[serde(rename = "E")]
+    #[wire = "E"]
     pub event_time: u64,
-    #[serde(rename = "T")]
+    #[wire = "T"]
     pub transaction_time: u64,
-    #[serde(rename = "a")]
+    #[wire = "a"]
     pub balance_position_update: BalancePositionUpdate,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BalancePositionUpdate {
-    #[serde(rename = "m")]
+    #[wire = "m"]
     pub reason: String,
-    #[serde(rename = "B")]
+    #[wire = "B"]
     pub balance_updates: Vec<BalanceUpdate>,
-    #[serde(rename = "P")]
+    #[wire = "P"]
     pub position_updates: Vec<PositionUpdate>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BalanceUpdate {
-    #[serde(rename = "a")]
+    #[wire = "a"]
     pub asset: String,
-    #[serde(rename = "wb")]
-    pub wallet_balance: String,
-    #[serde(rename = "cw")]
-    pub cross_wallet_balance: String,
-    #[serde(rename = "bc")]
-    pub balance_change: String,
+    #[wire = "wb"]
+    pub wallet_balance: Decimal,
+    #[wire = "cw"]
+    pub cross_wallet_balance: Decimal,
+    #[wire = "bc"]
+    pub balance_change: Decimal,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PositionUpdate {
-    #[serde(rename = "s")]
+    #[wire = "s"]
     pub symbol: String,
-    #[serde(rename = "pa")]
-    pub position_amount: String,
-    #[serde(rename = "ep")]
-    pub entry_price: String,
-    #[serde(rename = "bep")]
-    pub breakeven_price: String,
-    #[serde(rename = "cr")]
-    pub realized_pnl: String,
-    #[serde(rename = "up")]
-    pub unrealized_pnl: String,
-    #[serde(rename = "mt")]
+    #[wire = "pa"]
+    pub position_amount: Decimal,
+    #[wire = "ep"]
+    pub entry_price: Decimal,
+    #[wire = "bep"]
+    pub breakeven_price: Decimal,
+    #[wire = "cr"]
+    pub realized_pnl: Decimal,
+    #[wire = "up"]
+    pub unrealized_pnl: Decimal,
+    #[wire = "mt"]
     pub margin_type: String,
-    #[serde(rename = "iw")]
-    pub isolated_wallet: Option<String>,
-    #[serde(rename = "ps")]
-    pub position_side: String,
+    #[wire = "iw"]
+    pub isolated_wallet: Option<Decimal>,
+    #[wire = "ps"]
+    pub position_side: PositionSide,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct OrderUpdateEvent {
-    #[serde(rename = "E")]
+    #[wire = "E"]
     pub event_time: u64,
-    #[serde(rename = "T")]
+    #[wire = "T"]
     pub transaction_time: u64,
-    #[serde(rename = "o")]
+    #[wire = "o"]
     pub order_update: OrderUpdate,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct OrderUpdate {
-    #[serde(rename = "s")]
+    #[wire = "s"]
     pub symbol: String,
-    #[serde(rename = "c")]
+    #[wire = "c"]
     pub client_order_id: String,
-    #[serde(rename = "S")]
-    pub side: String,
-    #[serde(rename = "o")]
-    pub order_type: String,
-    #[serde(rename = "f")]
-    pub time_in_force: String,
-    #[serde(rename = "q")]
-    pub orig_qty: String,
-    #[serde(rename = "p")]
-    pub orig_price: String,
-    #[serde(rename = "ap")]
-    pub average_price: String,
-    #[serde(rename = "sp")]
-    pub stop_price: String,
-    #[serde(rename = "x")]
-    pub current_order_execution_type: String,
-    #[serde(rename = "X")]
-    pub current_order_status: String,
-    #[serde(rename = "i")]
+    #[wire = "S"]
+    pub side: Side,
+    #[wire = "o"]
+    pub order_type: OrderType,
+    #[wire = "f"]
+    pub time_in_force: TimeInForce,
+    #[wire = "q"]
+    pub orig_qty: Decimal,
+    #[wire = "p"]
+    pub orig_price: Decimal,
+    #[wire = "ap"]
+    pub average_price: Decimal,
+    #[wire = "sp"]
+    pub stop_price: Decimal,
+    #[wire = "x"]
+    pub current_order_execution_type: ExecutionType,
+    #[wire = "X"]
+    pub current_order_status: OrderStatus,
+    #[wire = "i"]
     pub order_id: u64,
-    #[serde(rename = "l")]
-    pub last_filled_qty: String,
-    #[serde(rename = "z")]
-    pub cummulative_filled_qty: String,
-    #[serde(rename = "L")]
-    pub last_fill_price: String,
-    #[serde(rename = "N")]
+    #[wire = "l"]
+    pub last_filled_qty: Decimal,
+    #[wire = "z"]
+    pub cummulative_filled_qty: Decimal,
+    #[wire = "L"]
+    pub last_fill_price: Decimal,
+    #[wire = "N"]
     pub commission_asset: Option<String>,
-    #[serde(rename = "n")]
-    pub commission_amount: Option<String>,
-    #[serde(rename = "T")]
+    #[wire = "n"]
+    pub commission_amount: Option<Decimal>,
+    #[wire = "T"]
     pub order_trade_time: u64,
-    #[serde(rename = "t")]
+    #[wire = "t"]
     pub order_trade_id: u64,
-    #[serde(rename = "b")]
-    pub bid_notional: String,
-    #[serde(rename = "a")]
-    pub ask_notional: String,
-    #[serde(rename = "m")]
+    #[wire = "b"]
+    pub bid_notional: Decimal,
+    #[wire = "a"]
+    pub ask_notional: Decimal,
+    #[wire = "m"]
     pub is_trade_maker: bool,
-    #[serde(rename = "R")]
+    #[wire = "R"]
     pub is_reduce_only: bool,
-    #[serde(rename = "wt")]
-    pub stop_price_working_type: String,
-    #[serde(rename = "ot")]
-    pub orig_order_type: String,
-    #[serde(rename = "ps")]
-    pub position_side: String,
-    #[serde(rename = "cp")]
+    #[wire = "wt"]
+    pub stop_price_working_type: WorkingType,
+    #[wire = "ot"]
+    pub orig_order_type: OrderType,
+    #[wire = "ps"]
+    pub position_side: PositionSide,
+    #[wire = "cp"]
     pub close_position: bool,
-    #[serde(rename = "AP")]
-    pub activation_price: Option<String>,
-    #[serde(rename = "cr")]
+    #[wire = "AP"]
+    pub activation_price: Option<Decimal>,
+    #[wire = "cr"]
     pub callback_rate: Option<String>,
-    #[serde(rename = "pP")]
+    #[wire = "pP"]
     pub price_protection: bool,
-    #[serde(rename = "rp")]
-    pub trade_realized_profit: String,
-    #[serde(rename = "V")]
-    pub self_trade_prevention_mode: String,
-    #[serde(rename = "gtd")]
+    #[wire = "rp"]
+    pub trade_realized_profit: Decimal,
+    #[wire = "V"]
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+    #[wire = "gtd"]
     pub good_till_date: u64,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct AccountConfigurationUpdateEvent {
-    #[serde(rename = "E")]
+    #[wire = "E"]
     pub event_time: u64,
-    #[serde(rename = "T")]
+    #[wire = "T"]
     pub transaction_time: u64,
-    #[serde(rename = "ac")]
+    #[wire = "ac"]
     pub leverage: Option<LeverageUpdate>,
-    #[serde(rename = "ai")]
+    #[wire = "ai"]
     pub multi_asset_mode: Option<MultiAssetModeUpdate>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct LeverageUpdate {
-    #[serde(rename = "s")]
+    #[wire = "s"]
     pub symbol: String,
-    #[serde(rename = "l")]
+    #[wire = "l"]
     pub leverage: u64,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct MultiAssetModeUpdate {
-    #[serde(rename = "j")]
+    #[wire = "j"]
     pub multi_asset_mode: bool,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ConditionalOrderTriggerRejectEvent {
-    #[serde(rename = "E")]
+    #[wire = "E"]
     pub event_time: u64,
-    #[serde(rename = "T")]
+    #[wire = "T"]
     pub transaction_time: u64,
-    #[serde(rename = "or")]
+    #[wire = "or"]
     pub conditional_order_trigger_reject: ConditionalOrderTriggerReject,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ConditionalOrderTriggerReject {
-    #[serde(rename = "s")]
+    #[wire = "s"]
     pub symbol: String,
-    #[serde(rename = "i")]
+    #[wire = "i"]
     pub order_id: u64,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[wire_names]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ListenKeyExpiredEvent {
-    #[serde(rename = "E")]
+    #[wire = "E"]
     pub event_time: u64,
-    #[serde(rename = "listenKey")]
+    #[wire = "listenKey"]
     pub listen_key: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(tag = "e")]
 #[allow(clippy::large_enum_variant)]
 pub enum UserStreamEvent {
@@ -372,4 +523,57 @@ mod tests {
             eprintln!("{:#?}", event);
         }
     }
+
+    #[tokio::test]
+    async fn test_borrowed_diff_depth_stream() {
+        let client = BinanceClient::usdm();
+        let stream_topic = DiffDepthStream { symbol: "BTCUSDT" };
+        let response = client.connect_stream(&stream_topic).await.unwrap();
+
+        let mut stream = response.content;
+
+        for _ in 0..5 {
+            let levels = stream
+                .next_borrowed(|event| {
+                    assert_eq!(event.symbol, "BTCUSDT");
+                    event.bid_updates.len() + event.ask_updates.len()
+                })
+                .await
+                .unwrap();
+            eprintln!("{} levels", levels);
+        }
+    }
+
+    #[test]
+    fn test_borrowed_events() {
+        let mut text = r#"{"e":"depthUpdate","E":123456789,"T":123456788,"s":"BTCUSDT","U":157,"u":160,"pu":149,"b":[["0.0024","10"]],"a":[["0.0026","100"],["0.0027","5"]]}"#.as_bytes().to_vec();
+        let event: DiffDepthEventRef = crate::json::parse_frame(&mut text);
+        assert_eq!(event.symbol, "BTCUSDT");
+        assert_eq!(event.bid_updates[0].price, "0.0024");
+        assert_eq!(event.ask_updates[1].qty, "5");
+    }
+
+    #[test]
+    fn test_event_round_trip() {
+        use crate::response::tests::assert_round_trip;
+        assert_round_trip::<DiffDepthEvent>(
+            r#"{"e":"depthUpdate","E":123456789,"T":123456788,"s":"BTCUSDT","U":157,"u":160,"pu":149,"b":[["0.0024","10"]],"a":[["0.0026","100"]]}"#,
+        );
+        assert_round_trip::<UserStreamEvent>(
+            r#"{"e":"ORDER_TRADE_UPDATE","E":1568879465651,"T":1568879465650,"o":{"s":"BTCUSDT","c":"TEST","S":"SELL","o":"TRAILING_STOP_MARKET","f":"GTC","q":"0.001","p":"0","ap":"0","sp":"7103.04","x":"NEW","X":"NEW","i":8886774,"l":"0","z":"0","L":"0","N":"USDT","n":"0","T":1568879465650,"t":0,"b":"0","a":"9.91","m":false,"R":false,"wt":"CONTRACT_PRICE","ot":"TRAILING_STOP_MARKET","ps":"LONG","cp":false,"AP":"7476.89","cr":"5.0","pP":false,"si":0,"ss":0,"rp":"0","V":"EXPIRE_TAKER","pm":"OPPONENT","gtd":0}}"#,
+        );
+    }
+
+    #[cfg(feature = "readable_names")]
+    #[test]
+    fn test_readable_names() {
+        let event: AggTradeEvent = serde_json::from_str(
+            r#"{"e":"aggTrade","E":123456789,"s":"BTCUSDT","a":5933014,"p":"0.001","q":"100","f":100,"l":105,"T":123456785,"m":true}"#,
+        )
+        .unwrap();
+        let value = serde_json::to_value(&event).unwrap();
+        assert_eq!(value["event_time"], 123456789);
+        assert_eq!(value["first_trade_id"], 100);
+        assert!(value.get("E").is_none());
+    }
 }
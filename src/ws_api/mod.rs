@@ -1,135 +1,166 @@
+mod params;
 pub mod spot;
 pub mod usdm;
 
 use crate::{
-    client::BinanceClient,
+    client::{BinanceClient, MarketUrls, WsApiMarket},
+    enums::{RateLimitInterval, RateLimitType},
     errors::{ContentError, WsConnectionError},
+    json::{parse_failed, parse_frame},
     response::Response,
 };
+use binance_async_api_macros::wire_names;
 use futures_util::{
-    stream::{Stream, StreamExt},
-    Sink, SinkExt,
+    stream::{SplitSink, SplitStream},
+    SinkExt, StreamExt,
 };
 use hex::encode as hexify;
 use hmac::{Hmac, Mac};
-use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
-use serde_json::{from_str, Value};
+use params::to_params;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{value::RawValue, Map, Value};
 use sha2::Sha256;
 use std::{
+    collections::HashMap,
     marker::PhantomData,
-    pin::Pin,
-    str::FromStr,
-    task::{Context, Poll},
+    sync::{Arc, Mutex, PoisonError},
+};
+use tokio::{net::TcpStream, sync::oneshot, task::JoinHandle};
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{Error as WsError, Message},
+    MaybeTlsStream, WebSocketStream,
 };
-use tokio::net::TcpStream;
-use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
 
 type WSStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct KeyedParams<R: Serialize> {
+struct KeyedParams {
     #[serde(flatten)]
-    params: R,
+    params: Map<String, Value>,
     api_key: String,
 }
 
 #[derive(Debug, Serialize)]
-struct SignedParams<R: Serialize> {
+#[serde(rename_all = "camelCase")]
+struct SignedParams {
     #[serde(flatten)]
-    params: R,
+    params: Map<String, Value>,
     api_key: String,
     signature: String,
 }
 
 #[derive(Debug, Serialize)]
-struct FullPublicRequest<R: Serialize> {
+struct FullPublicRequest {
     id: u64,
     method: &'static str,
-    params: R,
+    params: Map<String, Value>,
 }
 
 #[derive(Debug, Serialize)]
-struct FullKeyedRequest<R: Serialize> {
+struct FullKeyedRequest {
     id: u64,
     method: &'static str,
-    params: KeyedParams<R>,
+    params: KeyedParams,
 }
 
 #[derive(Debug, Serialize)]
-struct FullSignedRequest<R: Serialize> {
+struct FullSignedRequest {
     id: u64,
     method: &'static str,
-    params: SignedParams<R>,
+    params: SignedParams,
 }
 
+// A request message for market `T`, whose response parses as `R`
 #[derive(Debug, Clone)]
-pub struct WsApiRequest<T> {
+pub struct WsApiRequest<T, R> {
+    id: u64,
     raw: String,
-    _marker: PhantomData<T>,
+    order_entry: bool,
+    _marker: PhantomData<(T, R)>,
 }
 
-pub trait WsApiPublicRequest<T>: Serialize + Clone + Copy {
+pub trait WsApiPublicRequest<T: WsApiMarket>: Serialize + Clone {
+    type Response: DeserializeOwned;
+
     fn method(&self) -> &'static str;
 
-    fn build(self, id: u64) -> WsApiRequest<T>
+    fn build(self, id: u64) -> WsApiRequest<T, Self::Response>
     where
         Self: Sized,
     {
         let raw = public_req_into_message(id, self);
         WsApiRequest {
+            id,
             raw,
+            order_entry: false,
             _marker: PhantomData,
         }
     }
 }
 
-pub trait WsApiKeyedRequest<T>: Serialize + Clone + Copy {
+pub trait WsApiKeyedRequest<T: WsApiMarket>: Serialize + Clone {
+    type Response: DeserializeOwned;
+
     fn method(&self) -> &'static str;
 
-    fn build(self, id: u64, api_key: String) -> WsApiRequest<T>
+    fn build(self, id: u64, api_key: String) -> WsApiRequest<T, Self::Response>
     where
         Self: Sized,
     {
         let raw = keyed_req_into_message(id, self, api_key);
         WsApiRequest {
+            id,
             raw,
+            order_entry: false,
             _marker: PhantomData,
         }
     }
 }
-pub trait WsApiSignedRequest<T>: Serialize + Clone + Copy {
+
+pub trait WsApiSignedRequest<T: WsApiMarket>: Serialize + Clone {
+    // Places, modifies or cancels orders, which paper clients don't do on production
+    const ORDER_ENTRY: bool = false;
+    type Response: DeserializeOwned;
+
     fn method(&self) -> &'static str;
 
     fn timestamp(&self) -> u64;
     fn recv_window(&self) -> u64;
 
-    fn build(self, id: u64, api_key: String, api_secret: String) -> WsApiRequest<T>
+    fn build(self, id: u64, api_key: String, api_secret: String) -> WsApiRequest<T, Self::Response>
     where
         Self: Sized,
     {
         let raw = signed_req_into_message(id, self, api_key, api_secret);
         WsApiRequest {
+            id,
             raw,
+            order_entry: Self::ORDER_ENTRY,
             _marker: PhantomData,
         }
     }
 }
 
-fn public_req_into_message<T, R: WsApiPublicRequest<T>>(id: u64, req: R) -> String {
+fn public_req_into_message<T: WsApiMarket, R: WsApiPublicRequest<T>>(id: u64, req: R) -> String {
     let method = req.method();
     let full_req = FullPublicRequest {
         id,
         method,
-        params: req,
+        params: to_params(&req).unwrap(),
     };
     serde_json::to_string(&full_req).unwrap()
 }
 
-fn keyed_req_into_message<T, R: WsApiKeyedRequest<T>>(id: u64, req: R, api_key: String) -> String {
+fn keyed_req_into_message<T: WsApiMarket, R: WsApiKeyedRequest<T>>(
+    id: u64,
+    req: R,
+    api_key: String,
+) -> String {
     let method = req.method();
     let req_params = KeyedParams {
-        params: req,
+        params: to_params(&req).unwrap(),
         api_key,
     };
     let full_req = FullKeyedRequest {
@@ -140,16 +171,17 @@ fn keyed_req_into_message<T, R: WsApiKeyedRequest<T>>(id: u64, req: R, api_key:
     serde_json::to_string(&full_req).unwrap()
 }
 
-fn signed_req_into_message<T, R: WsApiSignedRequest<T>>(
+fn signed_req_into_message<T: WsApiMarket, R: WsApiSignedRequest<T>>(
     id: u64,
     req: R,
     api_key: String,
     api_secret: String,
 ) -> String {
     let method = req.method();
-    let signature = signature(&req, &api_secret);
+    let params = to_params(&req).unwrap();
+    let signature = signature(&params, &api_key, &api_secret);
     let req_params = SignedParams {
-        params: req,
+        params,
         api_key,
         signature,
     };
@@ -161,40 +193,49 @@ fn signed_req_into_message<T, R: WsApiSignedRequest<T>>(
     serde_json::to_string(&full_req).unwrap()
 }
 
-fn signature<T>(req: &impl WsApiSignedRequest<T>, api_secret: &str) -> String {
+fn signature(params: &Map<String, Value>, api_key: &str, api_secret: &str) -> String {
     let mut mac = Hmac::<Sha256>::new_from_slice(api_secret.as_bytes()).unwrap();
 
-    // Serialize the struct to a JSON object and sort the keys
-    let mut json_value = serde_json::to_value(req).unwrap();
-    let map = json_value.as_object_mut().unwrap();
+    // Add the api key to the params and sort the keys
+    let mut map = params.clone();
+    map.insert("apiKey".to_string(), Value::String(api_key.to_string()));
     map.sort_keys();
 
-    // Create the message to sign
-    let mut sign_message = String::new();
-    for (key, value) in map.iter() {
-        sign_message.push_str(&format!("{}={}&", key, value));
-    }
+    // Create the message to sign, strings are signed without their quotes
+    let sign_message = map
+        .iter()
+        .map(|(key, value)| match value {
+            Value::String(s) => format!("{}={}", key, s),
+            other => format!("{}={}", key, other),
+        })
+        .collect::<Vec<_>>()
+        .join("&");
 
     mac.update(sign_message.as_bytes());
     hexify(mac.finalize().into_bytes())
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[wire_names(camelCase)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct RateLimit {
-    pub rate_limit_type: String,
-    pub interval: String,
+    pub rate_limit_type: RateLimitType,
+    pub interval: RateLimitInterval,
     pub interval_num: u64,
     pub limit: u64,
     pub count: u64,
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[wire_names(camelCase)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct WsApiEvent<R: DeserializeOwned> {
     pub id: Option<u64>,
     pub status: u16,
-    #[serde(flatten, deserialize_with = "deserialize_result_field")]
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_result_field",
+        serialize_with = "serialize_result_field",
+        bound(serialize = "R: Serialize")
+    )]
     pub result: Result<R, ContentError>,
     pub rate_limits: Vec<RateLimit>,
 }
@@ -218,79 +259,212 @@ where
     }
 }
 
-pub trait WsApiResponse<T>: DeserializeOwned + Clone {}
+fn serialize_result_field<R, S>(
+    result: &Result<R, ContentError>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    R: Serialize,
+    S: Serializer,
+{
+    #[derive(Serialize)]
+    #[serde(untagged)]
+    enum RawResult<'a, R> {
+        Ok { result: &'a R },
+        Err { error: &'a ContentError },
+    }
 
-#[derive(Debug)]
-pub struct BinanceWsApi<R> {
-    stream: WSStream,
-    _marker: PhantomData<R>,
+    match result {
+        Ok(result) => RawResult::Ok { result }.serialize(serializer),
+        Err(error) => RawResult::Err::<'_, R> { error }.serialize(serializer),
+    }
 }
 
-impl<R: DeserializeOwned + Unpin> Stream for BinanceWsApi<R> {
-    type Item = WsApiEvent<R>;
+// A response frame with its result left as received, until it reaches the
+// call waiting for it, which knows the result's type. Only the result is
+// parsed with the `simd_json` feature, the rest is scanned by serde_json.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Envelope {
+    id: Option<u64>,
+    status: u16,
+    #[serde(default, deserialize_with = "raw_result")]
+    result: Option<Box<RawValue>>,
+    error: Option<ContentError>,
+    rate_limits: Vec<RateLimit>,
+}
 
-    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let msg = match self.stream.poll_next_unpin(cx) {
-            Poll::Ready(Some(Ok(c))) => c,
-            Poll::Ready(Some(Err(_))) | Poll::Ready(None) => return Poll::Ready(None),
-            Poll::Pending => return Poll::Pending,
-        };
-        let text = match msg {
-            Message::Text(msg) => msg,
-            Message::Binary(_) | Message::Frame(_) | Message::Pong(_) | Message::Ping(_) => {
-                cx.waker().wake_by_ref();
-                return Poll::Pending;
-            }
-            Message::Close(_) => return Poll::Ready(None),
-        };
+// Keeps a null result, which Option would take for a missing one
+fn raw_result<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Box<RawValue>>, D::Error> {
+    Box::<RawValue>::deserialize(deserializer).map(Some)
+}
 
-        let event = match from_str(&text) {
-            Ok(r) => r,
-            Err(e) => {
-                let val = Value::from_str(&text).unwrap();
-                eprintln!("Failed to parse event:");
-                eprintln!("{:#?}", val.as_object().unwrap());
-                panic!("parsing error: {}", e);
-            }
+impl Envelope {
+    fn into_event<R: DeserializeOwned>(self) -> WsApiEvent<R> {
+        let result = match (self.result, self.error) {
+            (Some(result), _) => Ok(parse_frame(
+                &mut Box::<str>::from(result).into_boxed_bytes(),
+            )),
+            (None, Some(error)) => Err(error),
+            (None, None) => unreachable!("checked by route_responses"),
         };
-
-        Poll::Ready(Some(event))
+        WsApiEvent {
+            id: self.id,
+            status: self.status,
+            result,
+            rate_limits: self.rate_limits,
+        }
     }
 }
 
-impl<T, R: WsApiResponse<T> + Unpin> Sink<WsApiRequest<T>> for BinanceWsApi<R> {
-    type Error = ();
+// Calls waiting for their response, by request id
+#[derive(Debug, Default)]
+struct Pending {
+    waiting: HashMap<u64, oneshot::Sender<Envelope>>,
+    closed: bool,
+}
 
-    fn start_send(mut self: Pin<&mut Self>, req: WsApiRequest<T>) -> Result<(), Self::Error> {
-        self.stream
-            .start_send_unpin(Message::Text(req.raw))
-            .map_err(|_| ())
+// Stops waiting when the call is dropped
+struct Waiting<'a> {
+    pending: &'a Mutex<Pending>,
+    id: u64,
+}
+
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        self.pending.lock().unwrap().waiting.remove(&self.id);
     }
+}
+
+// Closes the pending calls once routing stops, even on a frame that doesn't
+// parse. Dropping the senders tells the waiting calls.
+struct Closing(Arc<Mutex<Pending>>);
 
-    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
-        self.stream.poll_close_unpin(cx).map_err(|_| ())
+impl Drop for Closing {
+    fn drop(&mut self) {
+        let mut pending = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        pending.closed = true;
+        pending.waiting.clear();
     }
+}
 
-    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
-        self.stream.poll_flush_unpin(cx).map_err(|_| ())
+// Hands each response to the call waiting for its id. Responses nobody waits
+// for, like those to dropped calls, are skipped.
+async fn route_responses(mut stream: SplitStream<WSStream>, pending: Arc<Mutex<Pending>>) {
+    let pending = Closing(pending);
+    while let Some(Ok(msg)) = stream.next().await {
+        let text = match msg {
+            Message::Text(text) => text,
+            Message::Close(_) => break,
+            Message::Binary(_) | Message::Frame(_) | Message::Pong(_) | Message::Ping(_) => {
+                continue
+            }
+        };
+        let envelope: Envelope = match serde_json::from_str(&text) {
+            Ok(envelope) => envelope,
+            Err(e) => parse_failed(text.as_bytes(), e),
+        };
+        if envelope.result.is_none() && envelope.error.is_none() {
+            parse_failed(text.as_bytes(), "neither a result nor an error");
+        }
+        let Some(id) = envelope.id else {
+            continue;
+        };
+        if let Some(sender) = pending.0.lock().unwrap().waiting.remove(&id) {
+            let _ = sender.send(envelope);
+        }
     }
+}
 
-    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
-        self.stream.poll_ready_unpin(cx).map_err(|_| ())
+#[derive(Debug)]
+pub struct BinanceWsApi<T> {
+    sink: tokio::sync::Mutex<SplitSink<WSStream, Message>>,
+    pending: Arc<Mutex<Pending>>,
+    router: JoinHandle<()>,
+    refuse_order_entry: bool, // paper client connected to production
+    _marker: PhantomData<T>,
+}
+
+impl<T> Drop for BinanceWsApi<T> {
+    fn drop(&mut self) {
+        self.router.abort();
     }
 }
 
-impl<T> BinanceClient<T> {
-    pub async fn connect_ws_api<R: WsApiResponse<T>>(
+impl<T: WsApiMarket> BinanceWsApi<T> {
+    // Sends the request and waits for the response with its id. Several calls
+    // can wait at once, each gets its own response whatever the order they
+    // come in.
+    pub async fn request<R: DeserializeOwned>(
         &self,
-    ) -> Result<Response<BinanceWsApi<R>>, WsConnectionError> {
+        req: WsApiRequest<T, R>,
+    ) -> Result<WsApiEvent<R>, WsConnectionError> {
+        if req.order_entry && self.refuse_order_entry {
+            return Err(WsConnectionError::PaperMode);
+        }
+        let (sender, receiver) = oneshot::channel();
+        let _waiting = {
+            let mut pending = self.pending.lock().unwrap();
+            if pending.closed {
+                return Err(WsConnectionError::Closed);
+            }
+            if pending.waiting.contains_key(&req.id) {
+                return Err(WsConnectionError::DuplicateId(req.id));
+            }
+            pending.waiting.insert(req.id, sender);
+            Waiting {
+                pending: &self.pending,
+                id: req.id,
+            }
+        };
+
+        let sent = self.sink.lock().await.send(Message::Text(req.raw)).await;
+        if let Err(e) = sent {
+            let closed = matches!(e, WsError::ConnectionClosed | WsError::AlreadyClosed);
+            if closed || self.pending.lock().unwrap().closed {
+                return Err(WsConnectionError::Closed);
+            }
+            return Err(Box::new(e).into());
+        }
+        match receiver.await {
+            Ok(envelope) => Ok(envelope.into_event()),
+            Err(_) => Err(WsConnectionError::Closed),
+        }
+    }
+}
+
+impl<T: WsApiMarket + MarketUrls> BinanceClient<T> {
+    /// Only markets with a WS API have this method
+    ///
+    /// ```no_run
+    /// # async fn connect() {
+    /// let client = binance_async_api::client::BinanceClient::spot();
+    /// let ws_api = client.connect_ws_api().await;
+    /// # }
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # async fn connect() {
+    /// let client = binance_async_api::client::BinanceClient::coinm();
+    /// let ws_api = client.connect_ws_api().await;
+    /// # }
+    /// ```
+    pub async fn connect_ws_api(&self) -> Result<Response<BinanceWsApi<T>>, WsConnectionError> {
         let base = &self.config.ws_api_base_url;
         match connect_async(base).await {
             Ok((stream, response)) => {
                 let status_code = response.status();
                 let headers = Box::new(response.headers().clone());
+                let (sink, stream) = stream.split();
+                let pending = Arc::default();
                 let ws_api = BinanceWsApi {
-                    stream,
+                    sink: tokio::sync::Mutex::new(sink),
+                    router: tokio::spawn(route_responses(stream, Arc::clone(&pending))),
+                    pending,
+                    refuse_order_entry: self.config.paper
+                        && self.config.targets_production_ws_api(),
                     _marker: PhantomData,
                 };
                 Ok(Response {
@@ -303,3 +477,207 @@ impl<T> BinanceClient<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{ClientConfig, Environment, Spot},
+        enums::Side,
+        rest::json_array,
+        validation::tests::param,
+        ws_api::spot::{ExchangeInfoRequest, NewOrder, OpenOrdersRequest, RecentTradesRequest},
+    };
+    use tokio::net::TcpListener;
+
+    #[derive(Debug, Clone, Serialize)]
+    struct ListRequest {
+        #[serde(serialize_with = "json_array")]
+        symbols: Vec<&'static str>,
+        timestamp: u64,
+    }
+
+    impl WsApiSignedRequest<Spot> for ListRequest {
+        type Response = Value;
+
+        fn method(&self) -> &'static str {
+            "test"
+        }
+        fn timestamp(&self) -> u64 {
+            self.timestamp
+        }
+        fn recv_window(&self) -> u64 {
+            5000
+        }
+    }
+
+    #[test]
+    fn test_json_array_params() {
+        let req = ExchangeInfoRequest {
+            symbol: None,
+            symbols: Some(vec!["BTCUSDT".into(), "ETHUSDT".into()]),
+            permissions: None,
+            show_permission_sets: None,
+            symbol_status: None,
+        }
+        .build(1);
+        assert_eq!(
+            req.raw,
+            r#"{"id":1,"method":"exchangeInfo","params":{"symbols":["BTCUSDT","ETHUSDT"]}}"#
+        );
+
+        // Only json_array lists are sent as arrays, other strings stay strings
+        let req = ExchangeInfoRequest {
+            symbol: Some("[\"BTCUSDT\"]".into()),
+            symbols: None,
+            permissions: None,
+            show_permission_sets: None,
+            symbol_status: None,
+        }
+        .build(1);
+        assert_eq!(
+            req.raw,
+            r#"{"id":1,"method":"exchangeInfo","params":{"symbol":"[\"BTCUSDT\"]"}}"#
+        );
+
+        // Signed over apiKey=key&symbols=["BTCUSDT","ETHUSDT"]&timestamp=1
+        let req = ListRequest {
+            symbols: vec!["BTCUSDT", "ETHUSDT"],
+            timestamp: 1,
+        }
+        .build(
+            2,
+            "key".to_string(),
+            "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j".to_string(),
+        );
+        let message: Value = serde_json::from_str(&req.raw).unwrap();
+        assert_eq!(
+            message["params"]["symbols"],
+            serde_json::json!(["BTCUSDT", "ETHUSDT"])
+        );
+        assert_eq!(
+            message["params"]["signature"],
+            "5cab95a20eb05fc36c312bbfbbd088fb41e30945747adc5d6205db12e15dc73f"
+        );
+    }
+
+    // Answers each request it receives with the next frames of the script, then
+    // closes the connection
+    async fn mock_ws_api(script: Vec<Vec<&'static str>>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            for frames in script {
+                ws.next().await.unwrap().unwrap();
+                for frame in frames {
+                    ws.send(Message::Text(frame.to_string())).await.unwrap();
+                }
+            }
+            ws.close(None).await.unwrap();
+        });
+        url
+    }
+
+    #[tokio::test]
+    async fn test_typed_responses() {
+        let url = mock_ws_api(vec![
+            vec![
+                // Left over from a dropped call, and not a list of trades
+                r#"{"id":0,"status":200,"result":{"symbol":"BTCUSDT","price":"1"},"rateLimits":[]}"#,
+                r#"{"id":1,"status":200,"result":[],"rateLimits":[]}"#,
+            ],
+            vec![
+                r#"{"id":2,"status":400,"error":{"code":-1121,"msg":"Invalid symbol."},"rateLimits":[]}"#,
+            ],
+        ])
+        .await;
+        // Not a known testnet host, so taken for production
+        let config = ClientConfig::environment(Environment::Testnet).with_ws_api_base_url(url);
+        let client = BinanceClient::spot().with_config(config);
+        let ws_api = client.connect_ws_api().await.unwrap().content;
+
+        let req = RecentTradesRequest {
+            symbol: "BTCUSDT".into(),
+            limit: None,
+        }
+        .build(1);
+        let resp = ws_api.request(req).await.unwrap();
+        assert_eq!(resp.id, Some(1));
+        assert!(resp.result.unwrap().is_empty());
+
+        // Refused without being sent, or the mock would answer it
+        let req = NewOrder::market("BTCUSDT", Side::Buy, param("1").unwrap())
+            .build(0)
+            .build(3, "key".to_string(), "secret".to_string());
+        let res = ws_api.request(req).await;
+        assert!(matches!(res, Err(WsConnectionError::PaperMode)));
+
+        let req = OpenOrdersRequest {
+            symbol: Some("NOPE".into()),
+            recv_window: None,
+            timestamp: 0,
+        }
+        .build(2, "key".to_string(), "secret".to_string());
+        let resp = ws_api.request(req).await.unwrap();
+        assert_eq!(resp.result.unwrap_err().code, -1121);
+
+        let req = RecentTradesRequest {
+            symbol: "BTCUSDT".into(),
+            limit: None,
+        }
+        .build(4);
+        let res = ws_api.request(req).await;
+        assert!(matches!(res, Err(WsConnectionError::Closed)));
+    }
+
+    #[tokio::test]
+    async fn test_pipelined_requests() {
+        let url = mock_ws_api(vec![
+            vec![],
+            // Answered the other way around
+            vec![
+                r#"{"id":2,"status":400,"error":{"code":-1121,"msg":"Invalid symbol."},"rateLimits":[]}"#,
+                r#"{"id":1,"status":200,"result":[],"rateLimits":[]}"#,
+            ],
+            vec![r#"{"id":3,"status":200,"result":[],"rateLimits":[]}"#],
+        ])
+        .await;
+        let config = ClientConfig::environment(Environment::Testnet).with_ws_api_base_url(url);
+        let client = BinanceClient::spot().with_config(config);
+        let ws_api = client.connect_ws_api().await.unwrap().content;
+
+        let trades = |id| {
+            RecentTradesRequest {
+                symbol: "BTCUSDT".into(),
+                limit: None,
+            }
+            .build(id)
+        };
+        let (first, second) = tokio::join!(ws_api.request(trades(1)), ws_api.request(trades(2)));
+        assert!(first.unwrap().result.unwrap().is_empty());
+        assert_eq!(second.unwrap().result.unwrap_err().code, -1121);
+
+        // One call at a time per id
+        let (first, second) = tokio::join!(ws_api.request(trades(3)), ws_api.request(trades(3)));
+        assert!(first.unwrap().result.unwrap().is_empty());
+        assert!(matches!(second, Err(WsConnectionError::DuplicateId(3))));
+    }
+
+    #[tokio::test]
+    async fn test_unparsable_response_closes() {
+        let url = mock_ws_api(vec![vec![r#"{"id":1,"status":200}"#]]).await;
+        let config = ClientConfig::environment(Environment::Testnet).with_ws_api_base_url(url);
+        let client = BinanceClient::spot().with_config(config);
+        let ws_api = client.connect_ws_api().await.unwrap().content;
+        let req = RecentTradesRequest {
+            symbol: "BTCUSDT".into(),
+            limit: None,
+        }
+        .build(1);
+        // The frame is reported and the call doesn't hang
+        let res = ws_api.request(req).await;
+        assert!(matches!(res, Err(WsConnectionError::Closed)));
+    }
+}
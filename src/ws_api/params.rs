@@ -0,0 +1,166 @@
+use crate::rest::JSON_ARRAY;
+use serde::{
+    ser::{Error as _, SerializeMap, SerializeStruct},
+    Serialize, Serializer,
+};
+use serde_json::{value::Serializer as ValueSerializer, Error, Map, Value};
+
+// Serializes a request into the params object of a WS API message. Like a
+// query, except that `json_array` lists go in as arrays rather than as their
+// JSON text.
+pub(super) fn to_params(req: &impl Serialize) -> Result<Map<String, Value>, Error> {
+    match req.serialize(ParamSerializer)? {
+        Value::Object(params) => Ok(params),
+        _ => Err(Error::custom("request parameters must be a struct")),
+    }
+}
+
+struct ParamSerializer;
+
+macro_rules! forward {
+    ($($method:ident($($arg:ident: $ty:ty),*) -> $ok:ty;)+) => {$(
+        fn $method(self, $($arg: $ty),*) -> Result<$ok, Error> {
+            ValueSerializer.$method($($arg),*)
+        }
+    )+};
+}
+
+impl Serializer for ParamSerializer {
+    type Ok = Value;
+    type Error = Error;
+    type SerializeSeq = <ValueSerializer as Serializer>::SerializeSeq;
+    type SerializeTuple = <ValueSerializer as Serializer>::SerializeTuple;
+    type SerializeTupleStruct = <ValueSerializer as Serializer>::SerializeTupleStruct;
+    type SerializeTupleVariant = <ValueSerializer as Serializer>::SerializeTupleVariant;
+    type SerializeMap = Params;
+    type SerializeStruct = Params;
+    type SerializeStructVariant = <ValueSerializer as Serializer>::SerializeStructVariant;
+
+    forward! {
+        serialize_bool(v: bool) -> Value;
+        serialize_i8(v: i8) -> Value;
+        serialize_i16(v: i16) -> Value;
+        serialize_i32(v: i32) -> Value;
+        serialize_i64(v: i64) -> Value;
+        serialize_i128(v: i128) -> Value;
+        serialize_u8(v: u8) -> Value;
+        serialize_u16(v: u16) -> Value;
+        serialize_u32(v: u32) -> Value;
+        serialize_u64(v: u64) -> Value;
+        serialize_u128(v: u128) -> Value;
+        serialize_f32(v: f32) -> Value;
+        serialize_f64(v: f64) -> Value;
+        serialize_char(v: char) -> Value;
+        serialize_str(v: &str) -> Value;
+        serialize_bytes(v: &[u8]) -> Value;
+        serialize_none() -> Value;
+        serialize_unit() -> Value;
+        serialize_unit_struct(name: &'static str) -> Value;
+        serialize_unit_variant(name: &'static str, index: u32, variant: &'static str) -> Value;
+        serialize_seq(len: Option<usize>) -> Self::SerializeSeq;
+        serialize_tuple(len: usize) -> Self::SerializeTuple;
+        serialize_tuple_struct(name: &'static str, len: usize) -> Self::SerializeTupleStruct;
+        serialize_tuple_variant(
+            name: &'static str,
+            index: u32,
+            variant: &'static str,
+            len: usize
+        ) -> Self::SerializeTupleVariant;
+        serialize_struct_variant(
+            name: &'static str,
+            index: u32,
+            variant: &'static str,
+            len: usize
+        ) -> Self::SerializeStructVariant;
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        if name != JSON_ARRAY {
+            return value.serialize(self);
+        }
+        match value.serialize(ValueSerializer)? {
+            Value::String(json) => serde_json::from_str(&json),
+            _ => Err(Error::custom(
+                "a JSON array parameter must serialize as a string",
+            )),
+        }
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        ValueSerializer.serialize_newtype_variant(name, index, variant, value)
+    }
+
+    // Flattened requests, like the test orders, come as maps
+    fn serialize_map(self, _len: Option<usize>) -> Result<Params, Error> {
+        Ok(Params::default())
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Params, Error> {
+        Ok(Params::default())
+    }
+}
+
+#[derive(Default)]
+struct Params {
+    params: Map<String, Value>,
+    key: Option<String>,
+}
+
+impl SerializeStruct for Params {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.params
+            .insert(key.to_string(), value.serialize(ParamSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Object(self.params))
+    }
+}
+
+impl SerializeMap for Params {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        match key.serialize(ValueSerializer)? {
+            Value::String(key) => self.key = Some(key),
+            _ => return Err(Error::custom("parameter names must be strings")),
+        }
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| Error::custom("value without a name"))?;
+        self.params.insert(key, value.serialize(ParamSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Object(self.params))
+    }
+}
@@ -0,0 +1,469 @@
+use super::{WsApiPublicRequest, WsApiSignedRequest};
+use crate::client::Spot;
+
+pub use crate::rest::spot::*;
+
+impl WsApiPublicRequest<Spot> for ExchangeInfoRequest<'_> {
+    type Response = ExchangeInfoResponse;
+
+    fn method(&self) -> &'static str {
+        "exchangeInfo"
+    }
+}
+
+impl WsApiPublicRequest<Spot> for OrderBookRequest<'_> {
+    type Response = OrderBookResponse;
+
+    fn method(&self) -> &'static str {
+        "depth"
+    }
+}
+
+impl WsApiPublicRequest<Spot> for RecentTradesRequest<'_> {
+    type Response = Vec<TradeResponse>;
+
+    fn method(&self) -> &'static str {
+        "trades.recent"
+    }
+}
+
+impl WsApiPublicRequest<Spot> for HistoricalTradesRequest<'_> {
+    type Response = Vec<TradeResponse>;
+
+    fn method(&self) -> &'static str {
+        "trades.historical"
+    }
+}
+
+impl WsApiPublicRequest<Spot> for AggTradesRequest<'_> {
+    type Response = Vec<AggTradeResponse>;
+
+    fn method(&self) -> &'static str {
+        "trades.aggregate"
+    }
+}
+
+impl WsApiPublicRequest<Spot> for KlinesRequest<'_> {
+    type Response = Vec<KlineResponse>;
+
+    fn method(&self) -> &'static str {
+        "klines"
+    }
+}
+
+impl WsApiPublicRequest<Spot> for UiKlinesRequest<'_> {
+    type Response = Vec<KlineResponse>;
+
+    fn method(&self) -> &'static str {
+        "uiKlines"
+    }
+}
+
+impl WsApiPublicRequest<Spot> for AvgPriceRequest<'_> {
+    type Response = AvgPriceResponse;
+
+    fn method(&self) -> &'static str {
+        "avgPrice"
+    }
+}
+
+impl WsApiPublicRequest<Spot> for Ticker24hrRequest<'_> {
+    type Response = Tickers24hrResponse;
+
+    fn method(&self) -> &'static str {
+        "ticker.24hr"
+    }
+}
+
+impl WsApiPublicRequest<Spot> for RollingWindowTickerRequest<'_> {
+    type Response = RollingWindowTickersResponse;
+
+    fn method(&self) -> &'static str {
+        "ticker"
+    }
+}
+
+impl WsApiPublicRequest<Spot> for PriceTickerRequest<'_> {
+    type Response = PriceTickersResponse;
+
+    fn method(&self) -> &'static str {
+        "ticker.price"
+    }
+}
+
+impl WsApiPublicRequest<Spot> for BookTickerRequest<'_> {
+    type Response = BookTickersResponse;
+
+    fn method(&self) -> &'static str {
+        "ticker.book"
+    }
+}
+
+impl WsApiSignedRequest<Spot> for NewOrderRequest<'_> {
+    const ORDER_ENTRY: bool = true;
+    type Response = NewOrderResponse;
+
+    fn method(&self) -> &'static str {
+        "order.place"
+    }
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+impl WsApiSignedRequest<Spot> for TestNewOrderRequest<'_> {
+    type Response = TestNewOrderResponse;
+
+    fn method(&self) -> &'static str {
+        "order.test"
+    }
+
+    fn timestamp(&self) -> u64 {
+        self.order.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.order.recv_window.unwrap_or(5000)
+    }
+}
+
+impl WsApiSignedRequest<Spot> for QueryOrderRequest<'_> {
+    type Response = QueryOrderResponse;
+
+    fn method(&self) -> &'static str {
+        "order.status"
+    }
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+impl WsApiSignedRequest<Spot> for CancelOrderRequest<'_> {
+    const ORDER_ENTRY: bool = true;
+    type Response = CancelOrderResponse;
+
+    fn method(&self) -> &'static str {
+        "order.cancel"
+    }
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+impl WsApiSignedRequest<Spot> for OpenOrdersRequest<'_> {
+    type Response = Vec<QueryOrderResponse>;
+
+    fn method(&self) -> &'static str {
+        "openOrders.status"
+    }
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+impl WsApiSignedRequest<Spot> for CancelOpenOrdersRequest<'_> {
+    const ORDER_ENTRY: bool = true;
+    type Response = Vec<CancelOpenOrderResponse>;
+
+    fn method(&self) -> &'static str {
+        "openOrders.cancelAll"
+    }
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+impl WsApiSignedRequest<Spot> for NewOcoOrderListRequest<'_> {
+    const ORDER_ENTRY: bool = true;
+    type Response = NewOrderListResponse;
+
+    fn method(&self) -> &'static str {
+        "orderList.place.oco"
+    }
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+impl WsApiSignedRequest<Spot> for QueryOrderListRequest<'_> {
+    type Response = OrderListResponse;
+
+    fn method(&self) -> &'static str {
+        "orderList.status"
+    }
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+impl WsApiSignedRequest<Spot> for CancelOrderListRequest<'_> {
+    const ORDER_ENTRY: bool = true;
+    type Response = CancelOrderListResponse;
+
+    fn method(&self) -> &'static str {
+        "orderList.cancel"
+    }
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+impl WsApiSignedRequest<Spot> for OpenOrderListsRequest {
+    type Response = Vec<OrderListResponse>;
+
+    fn method(&self) -> &'static str {
+        "openOrderLists.status"
+    }
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+impl WsApiSignedRequest<Spot> for NewSorOrderRequest<'_> {
+    const ORDER_ENTRY: bool = true;
+    type Response = Vec<NewOrderResponse>;
+
+    fn method(&self) -> &'static str {
+        "sor.order.place"
+    }
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+impl WsApiSignedRequest<Spot> for TestNewSorOrderRequest<'_> {
+    type Response = TestNewOrderResponse;
+
+    fn method(&self) -> &'static str {
+        "sor.order.test"
+    }
+
+    fn timestamp(&self) -> u64 {
+        self.order.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.order.recv_window.unwrap_or(5000)
+    }
+}
+
+impl WsApiSignedRequest<Spot> for AccountInformationRequest {
+    type Response = AccountInformationResponse;
+
+    fn method(&self) -> &'static str {
+        "account.status"
+    }
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+impl WsApiSignedRequest<Spot> for CommissionRatesRequest<'_> {
+    type Response = CommissionRatesResponse;
+
+    fn method(&self) -> &'static str {
+        "account.commission"
+    }
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+impl WsApiSignedRequest<Spot> for AccountTradesRequest<'_> {
+    type Response = Vec<AccountTradeResponse>;
+
+    fn method(&self) -> &'static str {
+        "myTrades"
+    }
+
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    fn recv_window(&self) -> u64 {
+        self.recv_window.unwrap_or(5000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::BinanceClient,
+        enums::{OrderType, Side},
+        validation::tests::param,
+    };
+    use reqwest::StatusCode;
+    use serde_json::Value;
+
+    #[tokio::test]
+    async fn test_order_book_ws_api_request() {
+        let client = BinanceClient::spot();
+        let ws_api = client.connect_ws_api().await.unwrap().content;
+
+        let req = OrderBookRequest {
+            symbol: "BTCUSDT".into(),
+            limit: Some(5),
+        }
+        .build(0);
+        let resp = ws_api.request(req).await.unwrap();
+
+        eprintln!("{:#?}", resp);
+
+        assert!(resp.id == Some(0));
+        assert!(StatusCode::from_u16(resp.status).unwrap().is_success());
+        assert_eq!(resp.result.unwrap().bids.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_avg_price_ws_api_request() {
+        let client = BinanceClient::spot();
+        let ws_api = client.connect_ws_api().await.unwrap().content;
+
+        let req = AvgPriceRequest {
+            symbol: "BTCUSDT".into(),
+        }
+        .build(0);
+        let resp = ws_api.request(req).await.unwrap();
+
+        eprintln!("{:#?}", resp);
+
+        assert!(resp.id == Some(0));
+        assert!(StatusCode::from_u16(resp.status).unwrap().is_success());
+        assert!(resp.result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_paper_client_refuses_production_orders() {
+        use crate::{
+            client::{ClientConfig, Environment},
+            enums::Side,
+            errors::WsConnectionError,
+            validation::tests::param,
+        };
+
+        let config = ClientConfig::environment(Environment::Testnet)
+            .with_ws_api_base_url("wss://ws-api.binance.com:443/ws-api/v3".to_string());
+        let client = BinanceClient::spot().with_config(config);
+        let ws_api = client.connect_ws_api().await.unwrap().content;
+
+        let req = NewOrder::market("BTCUSDT", Side::Buy, param("0.001").unwrap())
+            .build(0)
+            .build(0, "key".to_string(), "secret".to_string());
+        let res = ws_api.request(req).await;
+        assert!(matches!(res, Err(WsConnectionError::PaperMode)));
+
+        // Market data is fine
+        let req = AvgPriceRequest {
+            symbol: "BTCUSDT".into(),
+        }
+        .build(1);
+        let resp = ws_api.request(req).await.unwrap();
+        assert!(resp.result.is_ok());
+    }
+
+    #[test]
+    fn test_response_round_trip() {
+        use crate::{response::tests::assert_round_trip, ws_api::WsApiEvent};
+        assert_round_trip::<WsApiEvent<AvgPriceResponse>>(
+            r#"{"id":1,"status":200,"result":{"mins":5,"price":"9.35751834","closeTime":1694061154503},"rateLimits":[{"rateLimitType":"REQUEST_WEIGHT","interval":"MINUTE","intervalNum":1,"limit":6000,"count":2}]}"#,
+        );
+        assert_round_trip::<WsApiEvent<NewOrderResponse>>(
+            r#"{"id":2,"status":200,"result":{"symbol":"BTCUSDT","orderId":12569099453,"orderListId":-1,"clientOrderId":"4d96324ff9d44481926157ec08158a40","transactTime":1660801715639},"rateLimits":[{"rateLimitType":"ORDERS","interval":"SECOND","intervalNum":10,"limit":50,"count":1}]}"#,
+        );
+        assert_round_trip::<WsApiEvent<QueryOrderResponse>>(
+            r#"{"id":3,"status":400,"error":{"code":-2013,"msg":"Order does not exist."},"rateLimits":[{"rateLimitType":"REQUEST_WEIGHT","interval":"MINUTE","intervalNum":1,"limit":6000,"count":4}]}"#,
+        );
+    }
+
+    #[test]
+    fn test_flattened_test_order_params() {
+        let req = TestNewOrderRequest {
+            order: NewOrderRequest {
+                symbol: "BTCUSDT".into(),
+                side: Side::Buy,
+                r#type: OrderType::Market,
+                time_in_force: None,
+                quantity: param("0.1"),
+                quote_order_qty: None,
+                price: None,
+                new_client_order_id: None,
+                strategy_id: None,
+                strategy_type: None,
+                stop_price: None,
+                trailing_delta: None,
+                iceberg_qty: None,
+                new_order_resp_type: None,
+                self_trade_prevention_mode: None,
+                recv_window: None,
+                timestamp: 1,
+            },
+            compute_commission_rates: Some(true),
+        };
+        let mut expected = serde_json::to_value(&req).unwrap();
+        let message = req.build(1, "key".to_string(), "secret".to_string());
+        let mut message: Value = serde_json::from_str(&message.raw).unwrap();
+        let params = message["params"].as_object_mut().unwrap();
+        assert!(params.remove("signature").is_some());
+        assert_eq!(params.remove("apiKey").unwrap(), "key");
+        assert_eq!(params, expected.as_object_mut().unwrap());
+    }
+
+    #[test]
+    fn test_ticker_symbols_params() {
+        let req = PriceTickerRequest {
+            symbol: None,
+            symbols: Some(vec!["BTCUSDT".into(), "ETHUSDT".into()]),
+        }
+        .build(1);
+        assert_eq!(
+            req.raw,
+            r#"{"id":1,"method":"ticker.price","params":{"symbols":["BTCUSDT","ETHUSDT"]}}"#
+        );
+    }
+}
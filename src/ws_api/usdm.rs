@@ -1,40 +1,51 @@
-use serde::Deserialize;
+use binance_async_api_macros::wire_names;
+use serde::{Deserialize, Serialize};
 
-use super::{WsApiPublicRequest, WsApiResponse, WsApiSignedRequest};
+use super::{WsApiPublicRequest, WsApiSignedRequest};
+use crate::{client::Usdm, decimal::Decimal};
 
 pub use crate::rest::usdm::*;
 
-impl<T> WsApiPublicRequest<T> for OrderBookRequest<'_> {
+impl WsApiPublicRequest<Usdm> for OrderBookRequest<'_> {
+    type Response = OrderBookResponse;
+
     fn method(&self) -> &'static str {
         "depth"
     }
 }
 
-impl<T> WsApiPublicRequest<T> for PriceTickerRequest<'_> {
+impl WsApiPublicRequest<Usdm> for PriceTickerRequest<'_> {
+    type Response = PriceTickerResponse;
+
     fn method(&self) -> &'static str {
         "ticker.price"
     }
 }
 
-impl<T> WsApiPublicRequest<T> for BookTickerRequest<'_> {
+impl WsApiPublicRequest<Usdm> for BookTickerRequest<'_> {
+    type Response = BookTickerResponse;
+
     fn method(&self) -> &'static str {
         "ticker.book"
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[wire_names(camelCase)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BookTickerResponse {
     pub last_update_id: u64,
     pub symbol: String,
-    pub bid_price: String,
-    pub bid_qty: String,
-    pub ask_price: String,
-    pub ask_qty: String,
+    pub bid_price: Decimal,
+    pub bid_qty: Decimal,
+    pub ask_price: Decimal,
+    pub ask_qty: Decimal,
     pub time: u64,
 }
 
-impl<T> WsApiSignedRequest<T> for NewOrderRequest<'_> {
+impl WsApiSignedRequest<Usdm> for NewOrderRequest<'_> {
+    const ORDER_ENTRY: bool = true;
+    type Response = NewOrderResponse;
+
     fn method(&self) -> &'static str {
         "order.place"
     }
@@ -47,7 +58,10 @@ impl<T> WsApiSignedRequest<T> for NewOrderRequest<'_> {
     }
 }
 
-impl<T> WsApiSignedRequest<T> for CancelOrderRequest<'_> {
+impl WsApiSignedRequest<Usdm> for CancelOrderRequest<'_> {
+    const ORDER_ENTRY: bool = true;
+    type Response = CancelOrderResponse;
+
     fn method(&self) -> &'static str {
         "order.cancel"
     }
@@ -60,90 +74,80 @@ impl<T> WsApiSignedRequest<T> for CancelOrderRequest<'_> {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(untagged)]
-pub enum WsApiResult {
-    OrderBook(OrderBookResponse),
-    PriceTicker(PriceTickerResponse),
-    BookTicker(BookTickerResponse),
-    NewOrder(NewOrderResponse),
-    CancelOrder(CancelOrderResponse),
-}
-
-impl<T> WsApiResponse<T> for WsApiResult {}
-
 #[cfg(test)]
 mod tests {
-    use super::{super::WsApiRequest, *};
-    use crate::client::{BinanceClient, Usdm};
-    use futures_util::{SinkExt, StreamExt};
+    use super::*;
+    use crate::client::BinanceClient;
     use reqwest::StatusCode;
 
     #[tokio::test]
     async fn test_order_book_ws_api_request() {
         let client = BinanceClient::usdm();
-        let mut ws_api = client
-            .connect_ws_api::<WsApiResult>()
-            .await
-            .unwrap()
-            .content;
+        let ws_api = client.connect_ws_api().await.unwrap().content;
 
         eprintln!("connected");
 
-        let req: WsApiRequest<Usdm> = OrderBookRequest {
-            symbol: "BTCUSDT",
+        let req = OrderBookRequest {
+            symbol: "BTCUSDT".into(),
             limit: Some(5),
         }
         .build(0);
-        ws_api.send(req).await.unwrap();
-        eprintln!("sent req");
-
-        let resp = ws_api.next().await.unwrap();
+        let resp = ws_api.request(req).await.unwrap();
 
         eprintln!("{:#?}", resp);
 
         assert!(resp.id == Some(0));
         assert!(StatusCode::from_u16(resp.status).unwrap().is_success());
-        assert!(matches!(resp.result, Ok(WsApiResult::OrderBook(_))));
+        assert_eq!(resp.result.unwrap().bids.len(), 5);
     }
 
     #[tokio::test]
     async fn test_price_ticker_ws_api_request() {
         let client = BinanceClient::usdm();
-        let mut ws_api = client
-            .connect_ws_api::<WsApiResult>()
-            .await
-            .unwrap()
-            .content;
+        let ws_api = client.connect_ws_api().await.unwrap().content;
 
-        let req: WsApiRequest<Usdm> = PriceTickerRequest { symbol: "BTCUSDT" }.build(0);
-        ws_api.send(req).await.unwrap();
-        let resp = ws_api.next().await.unwrap();
+        let req = PriceTickerRequest {
+            symbol: "BTCUSDT".into(),
+        }
+        .build(0);
+        let resp = ws_api.request(req).await.unwrap();
 
         eprintln!("{:#?}", resp);
 
         assert!(resp.id == Some(0));
         assert!(StatusCode::from_u16(resp.status).unwrap().is_success());
-        assert!(matches!(resp.result, Ok(WsApiResult::PriceTicker(_))));
+        assert_eq!(resp.result.unwrap().symbol, "BTCUSDT");
     }
 
     #[tokio::test]
     async fn test_book_ticker_ws_api_request() {
         let client = BinanceClient::usdm();
-        let mut ws_api = client
-            .connect_ws_api::<WsApiResult>()
-            .await
-            .unwrap()
-            .content;
+        let ws_api = client.connect_ws_api().await.unwrap().content;
 
-        let req: WsApiRequest<Usdm> = BookTickerRequest { symbol: "BTCUSDT" }.build(0);
-        ws_api.send(req).await.unwrap();
-        let resp = ws_api.next().await.unwrap();
+        let req = BookTickerRequest {
+            symbol: "BTCUSDT".into(),
+        }
+        .build(0);
+        let resp = ws_api.request(req).await.unwrap();
 
         eprintln!("{:#?}", resp);
 
         assert!(resp.id == Some(0));
         assert!(StatusCode::from_u16(resp.status).unwrap().is_success());
-        assert!(matches!(resp.result, Ok(WsApiResult::BookTicker(_))));
+        assert_eq!(resp.result.unwrap().symbol, "BTCUSDT");
+    }
+
+    #[test]
+    fn test_response_round_trip() {
+        use crate::{response::tests::assert_round_trip, ws_api::WsApiEvent};
+        assert_round_trip::<WsApiEvent<BookTickerResponse>>(
+            r#"{"id":1,"status":200,"result":{"lastUpdateId":1027024,"symbol":"BTCUSDT","bidPrice":"4","bidQty":"431","askPrice":"4.000002","askQty":"9","time":1589437530011},"rateLimits":[{"rateLimitType":"REQUEST_WEIGHT","interval":"MINUTE","intervalNum":1,"limit":2400,"count":2}]}"#,
+        );
+        assert_round_trip::<WsApiEvent<PriceTickerResponse>>(
+            r#"{"id":2,"status":200,"result":{"symbol":"BTCUSDT","price":"6000.01","time":1589437530011},"rateLimits":[{"rateLimitType":"REQUEST_WEIGHT","interval":"MINUTE","intervalNum":1,"limit":2400,"count":1}]}"#,
+        );
+        assert_round_trip::<WsApiEvent<NewOrderResponse>>(
+            r#"{"id":3,"status":400,"error":{"code":-2019,"msg":"Margin is insufficient."},"rateLimits":[{"rateLimitType":"ORDERS","interval":"MINUTE","intervalNum":1,"limit":1200,"count":1}]}"#,
+        );
     }
 }